@@ -51,3 +51,64 @@ pub const MAX_POOL_FOR_TOKEN_LAUNCH: u64 = 50_000_000_000; // 50 SOL in lamports
 /// Founder SOL vesting percentages (for excess SOL beyond 50)
 pub const FOUNDER_IMMEDIATE_SHARE_BPS: u64 = 800;  // 8% immediate
 pub const FOUNDER_VESTED_SHARE_BPS: u64 = 9200;     // 92% vested over 12 months
+
+/// Soft-close (anti-sniping) limits (in seconds)
+pub const MAX_SOFT_CLOSE_WINDOW: i64 = 60 * 60; // 1 hour maximum window
+pub const MAX_SOFT_CLOSE_EXTENSION: i64 = 60 * 60; // 1 hour maximum per-trade extension
+
+/// Outcome token decimals (matches share scaling of 1e9)
+pub const OUTCOME_TOKEN_DECIMALS: u8 = 9;
+
+/// Claim window after resolution (in seconds), configurable by admin within bounds
+pub const DEFAULT_CLAIM_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days
+pub const MIN_CLAIM_PERIOD: i64 = 7 * 24 * 60 * 60; // 7 days minimum
+pub const MAX_CLAIM_PERIOD: i64 = 365 * 24 * 60 * 60; // 1 year maximum
+
+/// Vesting schedule bounds (in seconds), configurable by founder via configure_vesting
+pub const VESTING_MONTH_SECONDS: i64 = 30 * 24 * 60 * 60; // 30-day month for step unlocks
+pub const DEFAULT_VESTING_DURATION: i64 = 12 * VESTING_MONTH_SECONDS; // 12 months (31,104,000 seconds)
+pub const MIN_VESTING_DURATION: i64 = VESTING_MONTH_SECONDS; // 1 month minimum
+pub const MAX_VESTING_DURATION: i64 = 48 * VESTING_MONTH_SECONDS; // 4 years maximum
+
+/// Maximum number of team vesting beneficiaries (see configure_team_split)
+pub const MAX_TEAM_BENEFICIARIES: usize = 10;
+
+/// Maximum number of founder vesting milestones (see configure_milestones)
+pub const MAX_FOUNDER_MILESTONES: usize = 5;
+
+/// Emergency pause flags (Treasury.paused globally, Market.paused per market), set via set_pause
+/// Claims, refunds and vesting instructions are never paused; switch_side is
+/// blocked while either buy flag is set
+pub const PAUSE_CREATE_MARKET: u8 = 1 << 0;
+pub const PAUSE_BUY_YES: u8 = 1 << 1;
+pub const PAUSE_BUY_NO: u8 = 1 << 2;
+pub const PAUSE_EXTEND_MARKET: u8 = 1 << 3;
+pub const PAUSE_RESOLVE_MARKET: u8 = 1 << 4;
+pub const PAUSE_ALL: u8 = PAUSE_CREATE_MARKET
+    | PAUSE_BUY_YES
+    | PAUSE_BUY_NO
+    | PAUSE_EXTEND_MARKET
+    | PAUSE_RESOLVE_MARKET;
+
+/// Treasury withdrawal timelock (in seconds), configurable by admin via configure_withdrawals
+pub const DEFAULT_WITHDRAWAL_DELAY: i64 = 48 * 60 * 60; // 48 hours
+pub const MIN_WITHDRAWAL_DELAY: i64 = 60 * 60; // 1 hour minimum
+pub const MAX_WITHDRAWAL_DELAY: i64 = 30 * 24 * 60 * 60; // 30 days maximum
+
+/// Maximum number of allowlisted treasury withdrawal recipients
+pub const MAX_WITHDRAWAL_RECIPIENTS: usize = 5;
+
+/// Admin council limits (see init_admin_council / propose_admin_action)
+pub const MAX_COUNCIL_MEMBERS: usize = 10;
+pub const ADMIN_PROPOSAL_TTL: i64 = 7 * 24 * 60 * 60; // proposals expire after 7 days
+
+/// Maximum length of the reason logged by emergency_refund (bytes)
+pub const MAX_EMERGENCY_REASON_LEN: usize = 200;
+
+/// Platform token staking (see init_staking_pool / distribute_staking_rewards)
+pub const STAKING_REWARD_PRECISION: u128 = 1_000_000_000_000; // reward_per_share scale (1e12)
+pub const STAKE_LOCK_PERIOD: i64 = 7 * 24 * 60 * 60; // each stake locks the position for 7 days
+
+/// Founder creation bond (see create_market / release_founder_bond / slash_founder_bond)
+pub const FOUNDER_BOND_BPS: u64 = 100; // 1% of target_pool, locked at creation
+pub const FOUNDER_BOND_LOCK_PERIOD: i64 = 30 * 24 * 60 * 60; // slashable for 30 days after resolution
@@ -10,7 +10,7 @@ pub enum ErrorCode {
     CannotResolveYet,
     #[msg("Market has already been resolved.")]
     AlreadyResolved,
-    #[msg("User already has a position on the opposite side (one position per wallet).")]
+    #[msg("User already has a position on the opposite side (one position per wallet, use switch_side).")]
     AlreadyHasPosition,
     #[msg("Investment amount is below the minimum required (0.01 SOL).")]
     InvestmentTooSmall,
@@ -34,7 +34,7 @@ pub enum ErrorCode {
     InvalidResolutionState,
     #[msg("Cannot close position - must claim rewards first or wait for refund state.")]
     CannotClosePosition,
-    #[msg("Cannot close market - claim period has not ended yet (30 days after expiry).")]
+    #[msg("Claim period has not ended yet.")]
     ClaimPeriodNotOver,
     #[msg("Cannot close market - pool still has unclaimed funds.")]
     PoolNotEmpty,
@@ -52,4 +52,74 @@ pub enum ErrorCode {
     AlreadyInitialized,
     #[msg("Nothing to claim at this time.")]
     NothingToClaim,
+    #[msg("Invalid soft-close configuration (window, extension or hard expiry out of bounds).")]
+    InvalidSoftCloseConfig,
+    #[msg("Market configuration can only change before the first trade.")]
+    MarketAlreadyTrading,
+    #[msg("Outcome token accounts are missing or do not match this market.")]
+    InvalidOutcomeTokenAccounts,
+    #[msg("Not available for outcome token markets - transfer or burn the outcome tokens instead.")]
+    OutcomeTokensEnabled,
+    #[msg("Claim period must be between 7 and 365 days.")]
+    InvalidClaimPeriod,
+    #[msg("Claim window has ended and unclaimed funds were swept.")]
+    ClaimPeriodOver,
+    #[msg("Token allocations are not fully claimed or swept yet.")]
+    AllocationsOutstanding,
+    #[msg("Cannot close market - market token account must be closed first.")]
+    TokenAccountNotClosed,
+    #[msg("Invalid vesting schedule: duration, cliff or curve out of bounds.")]
+    InvalidVestingSchedule,
+    #[msg("Invalid team split: 1-10 unique beneficiaries with shares summing to 100%.")]
+    InvalidTeamSplit,
+    #[msg("Vesting is not revocable for this market.")]
+    VestingNotRevocable,
+    #[msg("Voter vesting cannot be combined with outcome tokens.")]
+    VoterVestingUnsupported,
+    #[msg("Invalid milestones: up to 5 milestones, each > 0, together at most 100%.")]
+    InvalidMilestones,
+    #[msg("Milestone already attested.")]
+    MilestoneAlreadyAttested,
+    #[msg("Clawback has not been approved by a majority of YES shares.")]
+    ClawbackNotApproved,
+    #[msg("Clawback already executed - claim your share with claim_clawback.")]
+    ClawbackAlreadyExecuted,
+    #[msg("This instruction is paused by the platform admin.")]
+    InstructionPaused,
+    #[msg("Unknown pause flags.")]
+    InvalidPauseFlags,
+    #[msg("Emergency refund reason must be 1-200 bytes.")]
+    InvalidEmergencyReason,
+    #[msg("Invalid withdrawal policy: delay out of bounds or bad allowlist.")]
+    InvalidWithdrawalConfig,
+    #[msg("Recipient is not on the treasury withdrawal allowlist.")]
+    RecipientNotAllowed,
+    #[msg("Withdrawal is still timelocked.")]
+    WithdrawalTimelocked,
+    #[msg("Withdrawal exceeds the per-epoch cap.")]
+    WithdrawalCapExceeded,
+    #[msg("Invalid admin council: 1-10 unique members and 1 <= threshold <= members.")]
+    InvalidCouncil,
+    #[msg("Signer is not an admin council member.")]
+    NotCouncilMember,
+    #[msg("Council mode: pass an approved admin proposal for this action.")]
+    AdminProposalRequired,
+    #[msg("Admin proposal does not match this action, has expired or was already executed.")]
+    AdminProposalInvalid,
+    #[msg("Admin proposal does not have enough council approvals.")]
+    InsufficientApprovals,
+    #[msg("Staker share must be at most 10000 bps.")]
+    InvalidStakerShare,
+    #[msg("Stake amount must be greater than zero and at most the staked balance.")]
+    InvalidStakeAmount,
+    #[msg("This target pool requires a verified founder profile.")]
+    FounderNotVerified,
+    #[msg("Founder bond is still locked (resolution, launch steps or lock period pending).")]
+    BondLocked,
+    #[msg("Staked tokens are still locked (minimum stake duration not reached).")]
+    StakeLocked,
+    #[msg("Vesting was revoked or clawed back and can no longer be initialized.")]
+    VestingRevoked,
+    #[msg("Founder milestones are still unattested - the position keeps its YES vote.")]
+    MilestonesPending,
 }
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when the admin forces a market into Refund (emergency_refund)
+#[event]
+pub struct EmergencyRefundEvent {
+    /// Market forced into Refund
+    pub market: Pubkey,
+
+    /// Treasury admin who triggered the refund
+    pub admin: Pubkey,
+
+    /// Why the refund was triggered (shown to traders)
+    pub reason: String,
+
+    /// SOL moved from the vault into the market for refunds (lamports)
+    pub refund_pool: u64,
+
+    /// Unix timestamp of the refund
+    pub timestamp: i64,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Approve a pending admin proposal (council member only, once per member)
+#[derive(Accounts)]
+pub struct ApproveAdminAction<'info> {
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump,
+        constraint = admin_council.is_member(&member.key()) @ ErrorCode::NotCouncilMember
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_proposal", admin_proposal.id.to_le_bytes().as_ref()],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+
+    pub member: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApproveAdminAction>) -> Result<()> {
+    let admin_council = &ctx.accounts.admin_council;
+    let admin_proposal = &mut ctx.accounts.admin_proposal;
+    let member = ctx.accounts.member.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        !admin_proposal.executed && now <= admin_proposal.expires_at,
+        ErrorCode::AdminProposalInvalid
+    );
+    require!(
+        !admin_proposal.approvals.contains(&member),
+        ErrorCode::AlreadyClaimed
+    );
+
+    // Bounded by the space reserved for MAX_COUNCIL_MEMBERS approvals:
+    // drop approvals from members removed since, then add this one
+    admin_proposal.approvals.retain(|a| admin_council.is_member(a));
+    admin_proposal.approvals.push(member);
+
+    msg!("🏛️  Admin proposal #{} approved by {}", admin_proposal.id, member);
+    msg!(
+        "   Approvals: {} / {}",
+        admin_council.member_approvals(&admin_proposal.approvals),
+        admin_council.threshold
+    );
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Attest a founder milestone, releasing its slice of vested SOL
+///
+/// Two paths:
+/// 1. Reviewer: founder_milestones.reviewer signs → milestone attested immediately
+/// 2. YES holder vote: a holder passes their position and a fresh milestone_vote
+///    PDA; their YES shares are added to the milestone's votes, and it is
+///    attested once votes exceed half of market.total_yes_shares
+///
+/// Only for YesWins markets whose founder vesting has not been revoked.
+/// YesWins positions stay open while a milestone is unattested (close_position
+/// refuses them), so every YES share can still vote.
+#[derive(Accounts)]
+#[instruction(milestone_index: u8)]
+pub struct AttestMilestone<'info> {
+    #[account(
+        constraint = market.resolution == MarketResolution::YesWins @ ErrorCode::InvalidResolutionState,
+        constraint = market.founder_vesting_revoked_at == 0 @ ErrorCode::InvalidResolutionState
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"founder_milestones", market.key().as_ref()],
+        bump = founder_milestones.bump
+    )]
+    pub founder_milestones: Account<'info, FounderMilestones>,
+
+    /// Reviewer, or YES position holder voting
+    #[account(mut)]
+    pub attester: Signer<'info>,
+
+    /// Voter's position (vote path only)
+    #[account(
+        seeds = [b"position", market.key().as_ref(), attester.key().as_ref()],
+        bump = position.bump,
+        constraint = position.user == attester.key() @ ErrorCode::Unauthorized
+    )]
+    pub position: Option<Account<'info, Position>>,
+
+    /// Vote record (vote path only, creation fails if the holder already voted)
+    #[account(
+        init,
+        payer = attester,
+        space = MilestoneVote::SPACE,
+        seeds = [
+            b"milestone_vote",
+            market.key().as_ref(),
+            &[milestone_index],
+            attester.key().as_ref()
+        ],
+        bump
+    )]
+    pub milestone_vote: Option<Account<'info, MilestoneVote>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AttestMilestone>, milestone_index: u8) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let founder_milestones = &mut ctx.accounts.founder_milestones;
+    let attester = ctx.accounts.attester.key();
+    let index = milestone_index as usize;
+
+    require!(
+        index < founder_milestones.milestones.len(),
+        ErrorCode::InvalidMilestones
+    );
+    require!(
+        !founder_milestones.milestones[index].attested,
+        ErrorCode::MilestoneAlreadyAttested
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let reviewer = founder_milestones.reviewer;
+
+    if reviewer != Pubkey::default() && attester == reviewer {
+        // -------------------------
+        // 1) Reviewer attestation
+        // -------------------------
+
+        let milestone = &mut founder_milestones.milestones[index];
+        milestone.attested = true;
+        milestone.attested_at = now;
+
+        msg!("✅ Milestone {} attested by reviewer {}", index, attester);
+    } else {
+        // -------------------------
+        // 2) YES holder vote
+        // -------------------------
+
+        let (Some(position), Some(milestone_vote)) = (
+            ctx.accounts.position.as_ref(),
+            ctx.accounts.milestone_vote.as_mut(),
+        ) else {
+            return Err(ErrorCode::Unauthorized.into());
+        };
+
+        require!(position.yes_shares > 0, ErrorCode::InsufficientBalance);
+
+        milestone_vote.voter = attester;
+        milestone_vote.weight = position.yes_shares;
+        milestone_vote.bump = ctx.bumps.milestone_vote.ok_or(ErrorCode::MathError)?;
+
+        let attested =
+            founder_milestones.record_vote(index, position.yes_shares, market.total_yes_shares, now)?;
+        if attested {
+            msg!("✅ Milestone {} attested by YES holder majority", index);
+        }
+
+        msg!("🗳️  Milestone {} vote by {}", index, attester);
+        msg!(
+            "   Votes for: {} / {} YES shares",
+            founder_milestones.milestones[index].votes_for,
+            market.total_yes_shares
+        );
+    }
+
+    Ok(())
+}
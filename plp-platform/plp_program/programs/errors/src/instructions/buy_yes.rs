@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface};
 use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::state::*;
@@ -15,11 +16,14 @@ use crate::utils::amm::*;
 /// 5. Transfer net SOL (98.5%) → market vault
 /// 6. Calculate shares using Constant Product AMM (x * y = k)
 /// 7. Update position.yes_shares and AMM pools (yes_pool, no_pool)
+/// 8. Apply soft close: late trades push expiry_time out (if configured)
+/// 9. Mint YES outcome tokens 1:1 with shares (outcome token markets only)
 #[derive(Accounts)]
 pub struct BuyYes<'info> {
     #[account(
         mut,
-        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved
+        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved,
+        constraint = !market.is_paused(PAUSE_BUY_YES) @ ErrorCode::InstructionPaused
     )]
     pub market: Account<'info, Market>,
 
@@ -45,7 +49,8 @@ pub struct BuyYes<'info> {
     #[account(
         mut,
         seeds = [b"treasury"],
-        bump = treasury.bump
+        bump = treasury.bump,
+        constraint = !treasury.is_paused(PAUSE_BUY_YES) @ ErrorCode::InstructionPaused
     )]
     pub treasury: Account<'info, Treasury>,
 
@@ -53,6 +58,28 @@ pub struct BuyYes<'info> {
     pub user: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+    // -------------------------
+    // Outcome token accounts (required only when market.outcome_tokens_enabled)
+    // -------------------------
+
+    /// YES outcome token mint PDA
+    #[account(
+        mut,
+        seeds = [b"yes_mint", market.key().as_ref()],
+        bump
+    )]
+    pub yes_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// User's token account receiving YES outcome tokens
+    #[account(
+        mut,
+        token::mint = yes_mint,
+        token::authority = user,
+        token::token_program = token_program
+    )]
+    pub user_yes_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 pub fn handler(ctx: Context<BuyYes>, sol_amount: u64) -> Result<()> {
@@ -134,11 +161,10 @@ pub fn handler(ctx: Context<BuyYes>, sol_amount: u64) -> Result<()> {
         trade_fee,
     )?;
 
-    // Update treasury total fees
-    ctx.accounts.treasury.total_fees = ctx
-        .accounts
-        .treasury
-        .total_fees
+    // Update treasury fee ledger and market fee counter
+    ctx.accounts.treasury.record_fee(FeeSource::Trade, trade_fee)?;
+    market.trade_fees_generated = market
+        .trade_fees_generated
         .checked_add(trade_fee)
         .ok_or(ErrorCode::MathError)?;
 
@@ -222,5 +248,59 @@ pub fn handler(ctx: Context<BuyYes>, sol_amount: u64) -> Result<()> {
         .checked_add(actual_sol_amount)
         .ok_or(ErrorCode::MathError)?;
 
+    // -------------------------
+    // 6) Mint outcome tokens (outcome token markets only)
+    // -------------------------
+
+    if market.outcome_tokens_enabled {
+        let (Some(mint), Some(user_token_account), Some(token_program)) = (
+            ctx.accounts.yes_mint.as_ref(),
+            ctx.accounts.user_yes_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return Err(ErrorCode::InvalidOutcomeTokenAccounts.into());
+        };
+
+        // Track net SOL per side (backs outcome token refunds)
+        market.yes_net_invested = market
+            .yes_net_invested
+            .checked_add(net_amount)
+            .ok_or(ErrorCode::MathError)?;
+
+        // Create PDA signer seeds for market (mint authority)
+        let founder_key = market.founder;
+        let ipfs_hash = anchor_lang::solana_program::hash::hash(market.ipfs_cid.as_bytes());
+        let market_seeds = &[
+            b"market",
+            founder_key.as_ref(),
+            ipfs_hash.as_ref(),
+            &[market.bump],
+        ];
+        let signer_seeds = &[&market_seeds[..]];
+
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                MintTo {
+                    mint: mint.to_account_info(),
+                    to: user_token_account.to_account_info(),
+                    authority: market.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            shares,
+        )?;
+    }
+
+    // -------------------------
+    // 7) Anti-sniping soft close
+    // -------------------------
+
+    let new_expiry = market.soft_close_expiry(now);
+    if new_expiry > market.expiry_time {
+        msg!("⏱️  Late trade - expiry extended from {} to {}", market.expiry_time, new_expiry);
+        market.expiry_time = new_expiry;
+    }
+
     Ok(())
 }
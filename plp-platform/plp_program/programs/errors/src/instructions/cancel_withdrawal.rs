@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Cancel a queued treasury withdrawal (admin only)
+///
+/// The pending withdrawal account is closed (rent → admin).
+#[derive(Accounts)]
+pub struct CancelWithdrawal<'info> {
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"withdrawal", withdrawal.id.to_le_bytes().as_ref()],
+        bump = withdrawal.bump,
+        close = admin
+    )]
+    pub withdrawal: Account<'info, PendingWithdrawal>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<CancelWithdrawal>) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::CancelWithdrawal { id: ctx.accounts.withdrawal.id },
+    )?;
+
+    let withdrawal = &ctx.accounts.withdrawal;
+
+    msg!("🚫 Withdrawal #{} cancelled", withdrawal.id);
+    msg!("   Amount: {} lamports", withdrawal.amount);
+    msg!("   Recipient: {}", withdrawal.recipient);
+
+    Ok(())
+}
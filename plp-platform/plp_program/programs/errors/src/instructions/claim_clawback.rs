@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Claim a share of clawed back founder SOL and team tokens (YES holders)
+///
+/// share = clawback pool * position.yes_shares / market.total_yes_shares,
+/// minus what this position already received (the pool grows if
+/// execute_clawback runs again for a vesting omitted earlier).
+///
+/// Also pays out founder bonds slashed to YES voters (slash_founder_bond).
+/// Open until the claim deadline (pushed back by execute_clawback / slash_founder_bond).
+/// Token accounts are only required when tokens are owed (pass program ID otherwise).
+#[derive(Accounts)]
+pub struct ClaimClawback<'info> {
+    #[account(
+        mut,
+        constraint = market.resolution == MarketResolution::YesWins @ ErrorCode::InvalidResolutionState,
+        constraint = market.clawback_executed_at > 0 || market.clawback_sol > 0 @ ErrorCode::ClawbackNotApproved,
+        constraint = !market.unclaimed_swept @ ErrorCode::ClaimPeriodOver
+    )]
+    pub market: Account<'info, Market>,
+
+    /// User's position (weight = yes_shares)
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        constraint = position.user == user.key() @ ErrorCode::Unauthorized
+    )]
+    pub position: Account<'info, Position>,
+
+    /// YES holder claiming (receives the SOL share)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // -------------------------
+    // Token accounts (required only when tokens are owed)
+    // -------------------------
+
+    /// Market's token account (holds clawed back team tokens)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = market,
+        token::token_program = token_program
+    )]
+    pub market_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's token account (receives the token share)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = user,
+        token::token_program = token_program
+    )]
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Project token mint (decimals read from here)
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<ClaimClawback>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let position = &mut ctx.accounts.position;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now <= market.effective_claim_deadline(),
+        ErrorCode::ClaimPeriodOver
+    );
+
+    // -------------------------
+    // 1) Pro-rata share not yet paid to this position
+    // -------------------------
+
+    require!(position.yes_shares > 0, ErrorCode::InsufficientBalance);
+
+    let (token_share, sol_share) = market.clawback_share(position.yes_shares);
+    let tokens_owed = token_share.saturating_sub(position.clawback_tokens_claimed);
+    let sol_owed = sol_share.saturating_sub(position.clawback_sol_claimed);
+
+    require!(tokens_owed > 0 || sol_owed > 0, ErrorCode::NothingToClaim);
+
+    // -------------------------
+    // 2) Transfer tokens from market to user
+    // -------------------------
+
+    if tokens_owed > 0 {
+        let (Some(market_token_account), Some(user_token_account), Some(token_mint), Some(token_program)) = (
+            ctx.accounts.market_token_account.as_ref(),
+            ctx.accounts.user_token_account.as_ref(),
+            ctx.accounts.token_mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return Err(ErrorCode::InvalidAccountData.into());
+        };
+
+        require!(
+            Some(token_mint.key()) == market.token_mint,
+            ErrorCode::Unauthorized
+        );
+
+        // Create PDA signer seeds for market
+        let founder_key = market.founder;
+        let ipfs_hash = anchor_lang::solana_program::hash::hash(market.ipfs_cid.as_bytes());
+        let market_seeds = &[
+            b"market",
+            founder_key.as_ref(),
+            ipfs_hash.as_ref(),
+            &[market.bump],
+        ];
+        let signer_seeds = &[&market_seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: market_token_account.to_account_info(),
+                to: user_token_account.to_account_info(),
+                authority: market.to_account_info(),
+                mint: token_mint.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        token_interface::transfer_checked(transfer_ctx, tokens_owed, token_mint.decimals)?;
+
+        position.clawback_tokens_claimed = token_share;
+        market.clawback_tokens_claimed = market
+            .clawback_tokens_claimed
+            .checked_add(tokens_owed)
+            .ok_or(ErrorCode::MathError)?;
+    }
+
+    // -------------------------
+    // 3) Transfer SOL from market account to user
+    // -------------------------
+
+    if sol_owed > 0 {
+        // Never dip into the market account's own rent-exempt minimum
+        let rent = Rent::get()?;
+        let market_info = market.to_account_info();
+        let rent_exempt = rent.minimum_balance(market_info.data_len());
+        let available = market_info.lamports().saturating_sub(rent_exempt);
+        require!(sol_owed <= available, ErrorCode::InsufficientBalance);
+
+        **market_info.try_borrow_mut_lamports()? -= sol_owed;
+        **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? += sol_owed;
+
+        position.clawback_sol_claimed = sol_share;
+        market.clawback_sol_claimed = market
+            .clawback_sol_claimed
+            .checked_add(sol_owed)
+            .ok_or(ErrorCode::MathError)?;
+    }
+
+    msg!("↩️  Clawback claimed by {}", ctx.accounts.user.key());
+    msg!("   Tokens: {}", tokens_owed);
+    msg!("   SOL: {} lamports", sol_owed);
+
+    Ok(())
+}
@@ -4,8 +4,11 @@ use crate::state::*;
 
 /// Claim vested founder SOL
 ///
-/// Allows the founder to claim excess SOL based on linear vesting schedule (12 months)
+/// Allows the founder to claim excess SOL based on the market's founder vesting schedule (default 12 months linear)
 /// Can be called multiple times to claim unlocked SOL
+///
+/// Milestone-gated markets: each milestone's slice only unlocks once attested
+/// (see attest_milestone); pass founder_milestones when enabled.
 #[derive(Accounts)]
 pub struct ClaimFounderSol<'info> {
     #[account(
@@ -22,14 +25,18 @@ pub struct ClaimFounderSol<'info> {
     )]
     pub founder_vesting: Account<'info, FounderVesting>,
 
-    /// Founder wallet claiming SOL (must be market founder)
-    #[account(
-        mut,
-        constraint = founder.key() == market.founder @ ErrorCode::Unauthorized
-    )]
+    /// Vesting beneficiary claiming SOL (market founder, unless rotated via rotate_vesting_beneficiary)
+    #[account(mut)]
     pub founder: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Milestones gating part of the vested SOL (required when market.founder_milestones_enabled)
+    #[account(
+        seeds = [b"founder_milestones", market.key().as_ref()],
+        bump = founder_milestones.bump
+    )]
+    pub founder_milestones: Option<Account<'info, FounderMilestones>>,
 }
 
 pub fn handler(ctx: Context<ClaimFounderSol>) -> Result<()> {
@@ -40,8 +47,23 @@ pub fn handler(ctx: Context<ClaimFounderSol>) -> Result<()> {
     // 1) Calculate claimable SOL
     // -------------------------
 
-    let current_time = Clock::get()?.unix_timestamp;
-    let claimable = founder_vesting.calculate_claimable_sol(current_time)?;
+    // Unlocks stop accruing once vesting is revoked
+    let current_time = market.founder_vesting_time(Clock::get()?.unix_timestamp);
+    let milestones = if market.founder_milestones_enabled {
+        Some(
+            ctx.accounts
+                .founder_milestones
+                .as_deref()
+                .ok_or(ErrorCode::InvalidMilestones)?,
+        )
+    } else {
+        None
+    };
+    let claimable = founder_vesting.calculate_claimable_sol(
+        &market.founder_vesting_schedule,
+        milestones,
+        current_time,
+    )?;
 
     require!(claimable > 0, ErrorCode::NothingToClaim);
 
@@ -1,20 +1,21 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{self, TokenAccount, TokenInterface, TransferChecked};
-use crate::constants::PNL_WALLET;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::errors::ErrorCode;
 use crate::state::*;
-use std::str::FromStr;
 
 /// Claim platform's 2% token allocation (immediate, no vesting)
 ///
-/// Transfers tokens to hardcoded P&L wallet: 3MihVtsLsVuEccpmz4YG72Cr8CJWf1evRorTPdPiHeEQ
-/// Can only be called once after token launch
+/// Transfers tokens to the platform recipient (Treasury.platform_recipient,
+/// default P&L wallet 3MihVtsLsVuEccpmz4YG72Cr8CJWf1evRorTPdPiHeEQ).
+/// Can only be called once after token launch; see claim_platform_tokens_batch
+/// to collect many markets at once.
 #[derive(Accounts)]
 pub struct ClaimPlatformTokens<'info> {
     #[account(
         mut,
         constraint = market.token_mint.is_some() @ ErrorCode::InvalidResolutionState,
-        constraint = !market.platform_tokens_claimed @ ErrorCode::AlreadyClaimed
+        constraint = !market.platform_tokens_claimed @ ErrorCode::AlreadyClaimed,
+        constraint = market.treasury == treasury.key() @ ErrorCode::Unauthorized
     )]
     pub market: Account<'info, Market>,
 
@@ -26,28 +27,61 @@ pub struct ClaimPlatformTokens<'info> {
     )]
     pub market_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    /// P&L Platform wallet's token account (receives 2% allocation)
+    /// Platform recipient's token account (receives 2% allocation)
     #[account(
         mut,
-        constraint = pnl_token_account.owner == Pubkey::from_str(PNL_WALLET).unwrap() @ ErrorCode::Unauthorized,
+        constraint = pnl_token_account.owner == treasury.effective_platform_recipient() @ ErrorCode::Unauthorized,
         constraint = pnl_token_account.mint == market.token_mint.unwrap() @ ErrorCode::Unauthorized
     )]
     pub pnl_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    /// Token mint account
-    /// CHECK: Validated via token account constraints
-    pub token_mint: UncheckedAccount<'info>,
+    /// Token mint account (decimals read from here)
+    #[account(
+        address = market.token_mint.unwrap() @ ErrorCode::Unauthorized,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
 
-    /// Can be called by anyone (tokens always go to P&L wallet)
+    /// Can be called by anyone (tokens always go to the platform recipient)
     #[account(mut)]
     pub caller: Signer<'info>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Global Treasury PDA (platform recipient)
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
 }
 
 pub fn handler(ctx: Context<ClaimPlatformTokens>) -> Result<()> {
-    let market = &mut ctx.accounts.market;
+    let amount = transfer_platform_allocation(
+        &mut ctx.accounts.market,
+        &ctx.accounts.market_token_account,
+        &ctx.accounts.pnl_token_account,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    msg!("🏦 Platform tokens claimed: {}", amount);
+    msg!("   Recipient: {}", ctx.accounts.treasury.effective_platform_recipient());
 
+    Ok(())
+}
+
+/// Transfer a market's platform allocation (market PDA signs) and mark it claimed
+///
+/// Callers validate the accounts; shared by claim_platform_tokens and
+/// claim_platform_tokens_batch.
+pub fn transfer_platform_allocation<'info>(
+    market: &mut Account<'info, Market>,
+    market_token_account: &InterfaceAccount<'info, TokenAccount>,
+    recipient_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &AccountInfo<'info>,
+) -> Result<u64> {
     // Ensure tokens have been allocated
     require!(
         market.platform_tokens_allocated > 0,
@@ -55,7 +89,7 @@ pub fn handler(ctx: Context<ClaimPlatformTokens>) -> Result<()> {
     );
 
     // -------------------------
-    // Transfer tokens from market to P&L wallet
+    // Transfer tokens from market to platform recipient
     // -------------------------
 
     // Create PDA signer seeds for market account
@@ -70,17 +104,17 @@ pub fn handler(ctx: Context<ClaimPlatformTokens>) -> Result<()> {
     let signer_seeds = &[&market_seeds[..]];
 
     let transfer_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
+        token_program.clone(),
         TransferChecked {
-            from: ctx.accounts.market_token_account.to_account_info(),
-            to: ctx.accounts.pnl_token_account.to_account_info(),
+            from: market_token_account.to_account_info(),
+            to: recipient_token_account.to_account_info(),
             authority: market.to_account_info(),
-            mint: ctx.accounts.token_mint.to_account_info(),
+            mint: token_mint.to_account_info(),
         },
         signer_seeds,
     );
 
-    token_interface::transfer_checked(transfer_ctx, market.platform_tokens_allocated, 6)?; // Pump.fun tokens use 6 decimals
+    token_interface::transfer_checked(transfer_ctx, market.platform_tokens_allocated, token_mint.decimals)?;
 
     // -------------------------
     // Mark as claimed
@@ -88,5 +122,5 @@ pub fn handler(ctx: Context<ClaimPlatformTokens>) -> Result<()> {
 
     market.platform_tokens_claimed = true;
 
-    Ok(())
+    Ok(market.platform_tokens_allocated)
 }
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::errors::ErrorCode;
+use crate::state::*;
+use super::claim_platform_tokens::transfer_platform_allocation;
+
+/// Claim platform token allocations for many markets in one transaction (permissionless)
+///
+/// remaining_accounts: groups of 4 per market, in order
+/// - market (writable)
+/// - market token account (writable, owned by the market)
+/// - recipient token account (writable, owned by the platform recipient)
+/// - token mint
+///
+/// All mints must belong to `token_program`. Markets already claimed or
+/// without an allocation are skipped, so a batch never fails because one
+/// market was claimed individually in the meantime.
+#[derive(Accounts)]
+pub struct ClaimPlatformTokensBatch<'info> {
+    /// Global Treasury PDA (platform recipient)
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Anyone can claim (tokens always go to the platform recipient)
+    pub caller: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimPlatformTokensBatch<'info>>) -> Result<()> {
+    let treasury_key = ctx.accounts.treasury.key();
+    let recipient = ctx.accounts.treasury.effective_platform_recipient();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let remaining = ctx.remaining_accounts;
+
+    let groups = remaining.chunks_exact(4);
+    require!(
+        !remaining.is_empty() && groups.remainder().is_empty(),
+        ErrorCode::InvalidAccountData
+    );
+
+    let mut claimed: u32 = 0;
+    let mut skipped: u32 = 0;
+
+    for group in groups {
+        let mut market: Account<'info, Market> = Account::try_from(&group[0])?;
+        let market_token_account: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&group[1])?;
+        let recipient_token_account: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&group[2])?;
+        let token_mint: InterfaceAccount<'info, Mint> = InterfaceAccount::try_from(&group[3])?;
+
+        if market.platform_tokens_claimed || market.platform_tokens_allocated == 0 {
+            skipped += 1;
+            continue;
+        }
+
+        // -------------------------
+        // Same checks as claim_platform_tokens
+        // -------------------------
+
+        require!(market.treasury == treasury_key, ErrorCode::Unauthorized);
+        require!(
+            market.token_mint == Some(token_mint.key()),
+            ErrorCode::Unauthorized
+        );
+        require!(
+            *token_mint.to_account_info().owner == token_program.key(),
+            ErrorCode::Unauthorized
+        );
+        require!(
+            market_token_account.owner == market.key()
+                && market_token_account.mint == token_mint.key(),
+            ErrorCode::Unauthorized
+        );
+        require!(
+            recipient_token_account.owner == recipient
+                && recipient_token_account.mint == token_mint.key(),
+            ErrorCode::Unauthorized
+        );
+
+        let amount = transfer_platform_allocation(
+            &mut market,
+            &market_token_account,
+            &recipient_token_account,
+            &token_mint,
+            &token_program,
+        )?;
+
+        // Accounts loaded from remaining_accounts are not persisted automatically
+        market.exit(ctx.program_id)?;
+        claimed += 1;
+
+        msg!("   {}: {} tokens", market.key(), amount);
+    }
+
+    msg!("🏦 Platform tokens claimed for {} markets ({} skipped)", claimed, skipped);
+    msg!("   Recipient: {}", recipient);
+
+    Ok(())
+}
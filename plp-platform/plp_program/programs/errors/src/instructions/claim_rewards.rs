@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{self, TokenAccount, TokenInterface, TransferChecked};
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::errors::ErrorCode;
 use crate::state::*;
 
@@ -11,34 +11,65 @@ use crate::state::*;
 /// 3. Refund: All participants receive refund (invested - trading fees)
 ///
 /// Each user can only claim once (position.claimed flag)
+///
+/// Voter vesting markets (market.voter_vesting_duration > 0): YES voter tokens
+/// unlock linearly from resolution. Each call pays what unlocked since the last
+/// claim (tracked in position.voter_tokens_claimed) until the full share is paid.
+///
+/// YesWins positions stay open after the claim: their yes_shares weigh
+/// clawback votes and claims (close_position recovers the rent).
+///
+/// Outcome token markets: the claim is paid on outcome tokens instead of
+/// position shares. The user's whole balance of the passed outcome mint is
+/// burned and paid out pro-rata; the position (if any) is only closed.
+///
+/// Losing positions (YesWins without YES shares, NoWins without NO shares)
+/// can also be settled here: nothing is paid, the loss is recorded and the
+/// position closes (rent → user).
+///
+/// Every settled position updates the user's Reputation PDA (created on the
+/// first claim, user pays rent); see Reputation for what is recorded.
 #[derive(Accounts)]
 pub struct ClaimRewards<'info> {
     #[account(
         mut,
-        constraint = market.resolution != MarketResolution::Unresolved @ ErrorCode::InvalidResolutionState
+        constraint = market.resolution != MarketResolution::Unresolved @ ErrorCode::InvalidResolutionState,
+        constraint = !market.unclaimed_swept @ ErrorCode::ClaimPeriodOver
     )]
     pub market: Account<'info, Market>,
 
+    /// User's position (required for legacy markets, optional for outcome token markets)
     #[account(
         mut,
         seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
         bump = position.bump,
         constraint = position.market == market.key() @ ErrorCode::Unauthorized,
         constraint = position.user == user.key() @ ErrorCode::Unauthorized,
-        constraint = !position.claimed @ ErrorCode::AlreadyClaimed,
-        close = user  // 🔥 Close account and send rent to user
+        constraint = !position.claimed @ ErrorCode::AlreadyClaimed
     )]
-    pub position: Account<'info, Position>,
+    pub position: Option<Account<'info, Position>>,
 
-    /// Market's token account (for YES wins only)
-    /// CHECK: Validated when resolution == YesWins
-    #[account(mut)]
-    pub market_token_account: UncheckedAccount<'info>,
+    // -------------------------
+    // Token accounts (required only for YesWins, pass program ID otherwise)
+    // -------------------------
 
-    /// User's token account (for YES wins only)
-    /// CHECK: Validated when resolution == YesWins
-    #[account(mut)]
-    pub user_token_account: UncheckedAccount<'info>,
+    /// Market's token account (holds YES voter tokens)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = market,
+        token::token_program = token_program
+    )]
+    pub market_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's token account (receives YES voter tokens)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = user,
+        token::token_program = token_program
+    )]
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
     /// User claiming rewards
     #[account(mut)]
@@ -46,18 +77,196 @@ pub struct ClaimRewards<'info> {
 
     pub system_program: Program<'info, System>,
 
-    /// Token mint account (only used for YES wins)
-    /// CHECK: Only used for YES wins token transfers
-    pub token_mint: UncheckedAccount<'info>,
+    /// Project token mint (decimals read from here)
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL Token or Token-2022 program owning the mint
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// User's predictor track record
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = Reputation::SPACE,
+        seeds = [b"reputation", user.key().as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+
+    // -------------------------
+    // Outcome token accounts (required only when market.outcome_tokens_enabled)
+    // -------------------------
+
+    /// YES or NO outcome token mint being redeemed
+    #[account(mut)]
+    pub outcome_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// User's outcome token account (entire balance is burned)
+    #[account(
+        mut,
+        token::mint = outcome_mint,
+        token::authority = user,
+        token::token_program = outcome_token_program
+    )]
+    pub user_outcome_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub outcome_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+/// What a single claim pays out on
+struct ClaimBasis {
+    /// YES shares (or burned YES outcome tokens)
+    yes_shares: u64,
+    /// NO shares (or burned NO outcome tokens)
+    no_shares: u64,
+    /// Refund owed if the market resolved to Refund
+    refund_amount: u64,
+    /// Position's SOL invested (0 for outcome tokens)
+    invested: u64,
+}
 
-    /// Token program (only used for YES wins)
-    /// CHECK: Only used for YES wins token transfers
-    pub token_program: UncheckedAccount<'info>,
+/// Burn the user's outcome tokens and derive the claim basis from the burned amount
+fn burn_outcome_tokens(ctx: &Context<ClaimRewards>) -> Result<ClaimBasis> {
+    let market = &ctx.accounts.market;
+
+    let (Some(mint), Some(user_token_account), Some(token_program)) = (
+        ctx.accounts.outcome_mint.as_ref(),
+        ctx.accounts.user_outcome_token_account.as_ref(),
+        ctx.accounts.outcome_token_program.as_ref(),
+    ) else {
+        return Err(ErrorCode::InvalidOutcomeTokenAccounts.into());
+    };
+
+    // Outcome mint must be one of this market's PDA mints
+    let market_key = market.key();
+    let (yes_mint, _) = Pubkey::find_program_address(&[b"yes_mint", market_key.as_ref()], ctx.program_id);
+    let (no_mint, _) = Pubkey::find_program_address(&[b"no_mint", market_key.as_ref()], ctx.program_id);
+    let is_yes = mint.key() == yes_mint;
+    require!(
+        is_yes || mint.key() == no_mint,
+        ErrorCode::InvalidOutcomeTokenAccounts
+    );
+
+    let amount = user_token_account.amount;
+    require!(amount > 0, ErrorCode::InsufficientBalance);
+
+    token_interface::burn(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Burn {
+                mint: mint.to_account_info(),
+                from: user_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // Token refunds are paid at the side's average net price:
+    // refund = side_net_invested * tokens / side_total_shares
+    let (side_net_invested, side_total_shares) = if is_yes {
+        (market.yes_net_invested, market.total_yes_shares)
+    } else {
+        (market.no_net_invested, market.total_no_shares)
+    };
+    require!(side_total_shares > 0, ErrorCode::MathError);
+    let refund_amount = (amount as u128 * side_net_invested as u128
+        / side_total_shares as u128) as u64;
+
+    Ok(ClaimBasis {
+        yes_shares: if is_yes { amount } else { 0 },
+        no_shares: if is_yes { 0 } else { amount },
+        refund_amount,
+        invested: 0,
+    })
 }
 
 pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
+    let reputation = &mut ctx.accounts.reputation;
+    if reputation.wallet == Pubkey::default() {
+        reputation.wallet = ctx.accounts.user.key();
+        reputation.bump = ctx.bumps.reputation;
+    }
+
+    // -------------------------
+    // Determine claim basis (position shares or burned outcome tokens)
+    // -------------------------
+
+    let basis = if ctx.accounts.market.outcome_tokens_enabled {
+        burn_outcome_tokens(&ctx)?
+    } else {
+        use crate::constants::{TRADE_FEE_BPS, BPS_DIVISOR};
+
+        let position = ctx
+            .accounts
+            .position
+            .as_ref()
+            .ok_or(ErrorCode::Unauthorized)?;
+
+        // Refund invested amount minus trading fees (98.5% of invested)
+        // Trading fees (1.5%) were already paid to treasury during trades
+        // refund_amount = total_invested * (10000 - 150) / 10000 = 98.5% of invested
+        let refund_amount = (position.total_invested as u128 * (BPS_DIVISOR - TRADE_FEE_BPS) as u128
+            / BPS_DIVISOR as u128) as u64;
+
+        ClaimBasis {
+            yes_shares: position.yes_shares,
+            no_shares: position.no_shares,
+            refund_amount,
+            invested: position.total_invested,
+        }
+    };
+
+    // Positions are recorded in Reputation; outcome token claims are not
+    let record = !ctx.accounts.market.outcome_tokens_enabled;
+
+    // -------------------------
+    // Losing position: record the loss and close it (nothing to pay)
+    // -------------------------
+
+    let is_loss = record
+        && match ctx.accounts.market.resolution {
+            MarketResolution::YesWins => basis.yes_shares == 0,
+            MarketResolution::NoWins => basis.no_shares == 0,
+            _ => false,
+        };
+
+    if is_loss {
+        ctx.accounts
+            .reputation
+            .record_settlement(SettlementOutcome::Loss, basis.invested, 0)?;
+
+        let position = ctx
+            .accounts
+            .position
+            .as_mut()
+            .ok_or(ErrorCode::Unauthorized)?;
+        position.claimed = true;
+        position.close(ctx.accounts.user.to_account_info())?;
+
+        msg!("📉 Losing position settled ({} lamports invested)", basis.invested);
+        return Ok(());
+    }
+
+    // First voter vesting claim counts the win (later calls only add tokens)
+    // (Option::is_none_or needs Rust 1.82, newer than the Solana 1.18 toolchain)
+    #[allow(clippy::unnecessary_map_or)]
+    let first_claim = ctx
+        .accounts
+        .position
+        .as_ref()
+        .map_or(true, |position| position.voter_tokens_claimed == 0);
+
     let market = &mut ctx.accounts.market;
-    let position = &mut ctx.accounts.position;
+
+    // Amount paid by this claim (tokens for YesWins, lamports otherwise)
+    let paid: u64;
+
+    // Position stays open only for partially claimed voter vesting
+    let mut fully_claimed = true;
 
     // -------------------------
     // Process claim based on resolution
@@ -66,44 +275,56 @@ pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
     match market.resolution {
         MarketResolution::YesWins => {
             // YES voters receive tokens (proportional to yes_shares out of 65% allocation)
-            require!(position.yes_shares > 0, ErrorCode::InsufficientBalance);
+            require!(basis.yes_shares > 0, ErrorCode::InsufficientBalance);
             require!(market.total_yes_shares > 0, ErrorCode::MathError);
             require!(market.yes_voter_tokens_allocated > 0, ErrorCode::InsufficientBalance);
 
             // Calculate user's proportional token claim
-            // user_tokens = (user_yes_shares / total_yes_shares) * yes_voter_tokens_allocated
-            let user_tokens = ((position.yes_shares as u128 * market.yes_voter_tokens_allocated as u128)
+            // user_share = (user_yes_shares / total_yes_shares) * yes_voter_tokens_allocated
+            let user_share = ((basis.yes_shares as u128 * market.yes_voter_tokens_allocated as u128)
                 / market.total_yes_shares as u128) as u64;
 
+            // Voter vesting: only the part unlocked since the last claim
+            let user_tokens = if let Some(schedule) = market.voter_vesting_schedule() {
+                let position = ctx
+                    .accounts
+                    .position
+                    .as_mut()
+                    .ok_or(ErrorCode::Unauthorized)?;
+
+                let now = Clock::get()?.unix_timestamp;
+                let unlocked = schedule.unlocked_amount(user_share, market.resolved_at, now);
+                let claimable = unlocked.saturating_sub(position.voter_tokens_claimed);
+
+                position.voter_tokens_claimed = position
+                    .voter_tokens_claimed
+                    .checked_add(claimable)
+                    .ok_or(ErrorCode::MathError)?;
+                fully_claimed = position.voter_tokens_claimed >= user_share;
+
+                claimable
+            } else {
+                user_share
+            };
+
             require!(user_tokens > 0, ErrorCode::InsufficientBalance);
 
             // -------------------------
             // Transfer tokens from market to user
             // -------------------------
 
-            // Deserialize token accounts for validation
-            let market_token_acct = TokenAccount::try_deserialize(
-                &mut &ctx.accounts.market_token_account.try_borrow_data()?[..]
-            )?;
-            let user_token_acct = TokenAccount::try_deserialize(
-                &mut &ctx.accounts.user_token_account.try_borrow_data()?[..]
-            )?;
+            let (Some(market_token_account), Some(user_token_account), Some(token_mint), Some(token_program)) = (
+                ctx.accounts.market_token_account.as_ref(),
+                ctx.accounts.user_token_account.as_ref(),
+                ctx.accounts.token_mint.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return Err(ErrorCode::InvalidAccountData.into());
+            };
 
-            // Validate token accounts
-            require!(
-                market_token_acct.owner == market.key(),
-                ErrorCode::Unauthorized
-            );
-            require!(
-                user_token_acct.owner == ctx.accounts.user.key(),
-                ErrorCode::Unauthorized
-            );
-            require!(
-                market_token_acct.mint == market.token_mint.unwrap(),
-                ErrorCode::Unauthorized
-            );
+            // Token accounts are validated against token_mint by the account constraints
             require!(
-                user_token_acct.mint == market.token_mint.unwrap(),
+                Some(token_mint.key()) == market.token_mint,
                 ErrorCode::Unauthorized
             );
 
@@ -120,29 +341,37 @@ pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
 
             // Transfer tokens via CPI
             let transfer_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
+                token_program.to_account_info(),
                 TransferChecked {
-                    from: ctx.accounts.market_token_account.to_account_info(),
-                    to: ctx.accounts.user_token_account.to_account_info(),
+                    from: market_token_account.to_account_info(),
+                    to: user_token_account.to_account_info(),
                     authority: market.to_account_info(),
-                    mint: ctx.accounts.token_mint.to_account_info(),
+                    mint: token_mint.to_account_info(),
                 },
                 signer_seeds,
             );
 
-            token_interface::transfer_checked(transfer_ctx, user_tokens, 6)?; // Pump.fun tokens use 6 decimals
+            token_interface::transfer_checked(transfer_ctx, user_tokens, token_mint.decimals)?;
+
+            // Track claimed voter tokens (unclaimed remainder can be swept after the deadline)
+            market.yes_voter_tokens_claimed = market
+                .yes_voter_tokens_claimed
+                .checked_add(user_tokens)
+                .ok_or(ErrorCode::MathError)?;
+
+            paid = user_tokens;
         }
 
         MarketResolution::NoWins => {
             // NO voters receive SOL (proportional to no_shares)
-            require!(position.no_shares > 0, ErrorCode::InsufficientBalance);
+            require!(basis.no_shares > 0, ErrorCode::InsufficientBalance);
             require!(market.total_no_shares > 0, ErrorCode::MathError);
             require!(market.distribution_pool > 0, ErrorCode::InsufficientBalance);
 
             // Calculate proportional payout using fixed distribution pool
             // payout = (user_no_shares / total_no_shares) * distribution_pool
             // This ensures fair distribution regardless of claim order
-            let user_payout = ((position.no_shares as u128 * market.distribution_pool as u128)
+            let user_payout = ((basis.no_shares as u128 * market.distribution_pool as u128)
                 / market.total_no_shares as u128) as u64;
 
             require!(user_payout > 0, ErrorCode::InsufficientBalance);
@@ -160,20 +389,13 @@ pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
                 .pool_balance
                 .checked_sub(user_payout)
                 .ok_or(ErrorCode::MathError)?;
+
+            paid = user_payout;
         }
 
         MarketResolution::Refund => {
-            // Refund invested amount minus trading fees (98.5% of invested)
-            // Trading fees (1.5%) were already paid to treasury during trades
-            use crate::constants::{TRADE_FEE_BPS, BPS_DIVISOR};
-
-            let total_invested = position.total_invested;
-            require!(total_invested > 0, ErrorCode::InsufficientBalance);
-
-            // Calculate net refund: invested - trading_fees
-            // refund_amount = total_invested * (10000 - 150) / 10000 = 98.5% of invested
-            let refund_amount = (total_invested as u128 * (BPS_DIVISOR - TRADE_FEE_BPS) as u128
-                / BPS_DIVISOR as u128) as u64;
+            // Refund net invested amount (98.5% of invested, see claim basis above)
+            let refund_amount = basis.refund_amount;
 
             require!(refund_amount > 0, ErrorCode::InsufficientBalance);
 
@@ -190,6 +412,8 @@ pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
                 .pool_balance
                 .checked_sub(refund_amount)
                 .ok_or(ErrorCode::MathError)?;
+
+            paid = refund_amount;
         }
 
         MarketResolution::Unresolved => {
@@ -198,21 +422,46 @@ pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
         }
     }
 
+    // -------------------------
+    // Update reputation
+    // -------------------------
+
+    if record {
+        let reputation = &mut ctx.accounts.reputation;
+        match market.resolution {
+            MarketResolution::YesWins => {
+                reputation.record_token_claim(basis.invested, paid, first_claim)?
+            }
+            MarketResolution::NoWins => {
+                reputation.record_settlement(SettlementOutcome::Win, basis.invested, paid)?
+            }
+            _ => reputation.record_settlement(SettlementOutcome::Refund, basis.invested, paid)?,
+        }
+    }
+
+    if !fully_claimed {
+        return Ok(());
+    }
+
     // -------------------------
     // Mark position as claimed
     // -------------------------
 
-    position.claimed = true;
+    if let Some(position) = ctx.accounts.position.as_mut() {
+        position.claimed = true;
+    }
 
     // -------------------------
-    // 🔥 RENT RECOVERY: Position PDA will be closed automatically
+    // 🔥 RENT RECOVERY: close Position PDA (if passed), rent goes to user
     // -------------------------
-    // The `close = user` constraint in the account struct will:
-    // 1. Zero out the position account data
-    // 2. Transfer all lamports (rent) to the user
-    // 3. Mark account for garbage collection
-    //
-    // This happens AFTER this function returns, handled by Anchor framework
+    // Closed here rather than via `close = user` because YesWins positions
+    // stay open (voter vesting claims, clawback weight)
+
+    if let Some(position) = ctx.accounts.position.as_ref() {
+        if market.resolution != MarketResolution::YesWins {
+            position.close(ctx.accounts.user.to_account_info())?;
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Pay a staker their accrued SOL rewards (permissionless)
+///
+/// Anyone can call; rewards always go to stake_position.owner.
+#[derive(Accounts)]
+pub struct ClaimStakingRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_pool"],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", owner.key().as_ref()],
+        bump = stake_position.bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    /// CHECK: Stake owner receiving the rewards (seeds bind it to stake_position)
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    pub caller: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimStakingRewards>) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let stake_position = &mut ctx.accounts.stake_position;
+
+    // -------------------------
+    // 1) Settle rewards earned so far
+    // -------------------------
+
+    stake_position.settle(staking_pool.reward_per_share)?;
+    let amount = stake_position.unclaimed;
+    require!(amount > 0, ErrorCode::AlreadyClaimed);
+
+    // -------------------------
+    // 2) Pay owner from the staking pool account
+    // -------------------------
+
+    let pool_info = staking_pool.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(pool_info.data_len());
+    let available = pool_info.lamports().saturating_sub(rent_exempt);
+    require!(amount <= available, ErrorCode::InsufficientBalance);
+
+    **pool_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.owner.try_borrow_mut_lamports()? += amount;
+
+    stake_position.unclaimed = 0;
+    stake_position.total_claimed = stake_position
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathError)?;
+    staking_pool.total_claimed = staking_pool
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathError)?;
+
+    msg!("🥩 Staking rewards claimed");
+    msg!("   Owner: {}", stake_position.owner);
+    msg!("   Amount: {} lamports", amount);
+
+    Ok(())
+}
@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{self, TokenAccount, TokenInterface, TransferChecked};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::errors::ErrorCode;
 use crate::state::*;
 
 /// Claim vested team tokens
 ///
-/// Allows the team to claim tokens based on linear vesting schedule (12 months)
+/// Allows the team to claim tokens based on the market's team vesting schedule (default 12 months linear)
 /// Can be called multiple times to claim unlocked tokens
+///
+/// Split markets (market.team_split_enabled): each beneficiary in the TeamSplit
+/// signs as team_wallet and claims their own share of the unlocked allocation.
 #[derive(Accounts)]
 pub struct ClaimTeamTokens<'info> {
     #[account(
@@ -18,8 +21,7 @@ pub struct ClaimTeamTokens<'info> {
     #[account(
         mut,
         seeds = [b"team_vesting", market.key().as_ref()],
-        bump = team_vesting.bump,
-        constraint = team_vesting.team_wallet == team_wallet.key() @ ErrorCode::Unauthorized
+        bump = team_vesting.bump
     )]
     pub team_vesting: Account<'info, TeamVesting>,
 
@@ -39,15 +41,26 @@ pub struct ClaimTeamTokens<'info> {
     )]
     pub team_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    /// Team wallet claiming tokens
+    /// Team wallet claiming tokens (team_vesting.team_wallet, or a beneficiary on split markets)
     #[account(mut)]
     pub team_wallet: Signer<'info>,
 
-    /// Token mint account
-    /// CHECK: Validated via token account constraints
-    pub token_mint: UncheckedAccount<'info>,
+    /// Token mint account (decimals read from here)
+    #[account(
+        address = team_vesting.token_mint @ ErrorCode::Unauthorized,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Beneficiary split (required when market.team_split_enabled)
+    #[account(
+        mut,
+        seeds = [b"team_split", market.key().as_ref()],
+        bump = team_split.bump
+    )]
+    pub team_split: Option<Account<'info, TeamSplit>>,
 }
 
 pub fn handler(ctx: Context<ClaimTeamTokens>) -> Result<()> {
@@ -58,8 +71,34 @@ pub fn handler(ctx: Context<ClaimTeamTokens>) -> Result<()> {
     // 1) Calculate claimable tokens
     // -------------------------
 
-    let current_time = Clock::get()?.unix_timestamp;
-    let claimable = team_vesting.calculate_claimable_tokens(current_time)?;
+    // Unlocks stop accruing once vesting is revoked
+    let current_time = market.team_vesting_time(Clock::get()?.unix_timestamp);
+    let team_wallet = ctx.accounts.team_wallet.key();
+
+    let claimable = if market.team_split_enabled {
+        // Beneficiary's share of everything unlocked so far, minus what they already claimed
+        let team_split = ctx
+            .accounts
+            .team_split
+            .as_mut()
+            .ok_or(ErrorCode::InvalidTeamSplit)?;
+        let index = team_split.find(&team_wallet).ok_or(ErrorCode::Unauthorized)?;
+
+        let unlocked = team_vesting
+            .calculate_unlocked_total_tokens(&market.team_vesting_schedule, current_time)?;
+        let entitled = team_split.share_of(index, unlocked);
+
+        let beneficiary = &mut team_split.beneficiaries[index];
+        let claimable = entitled.saturating_sub(beneficiary.claimed_tokens);
+        beneficiary.claimed_tokens = beneficiary
+            .claimed_tokens
+            .checked_add(claimable)
+            .ok_or(ErrorCode::MathError)?;
+        claimable
+    } else {
+        require!(team_vesting.team_wallet == team_wallet, ErrorCode::Unauthorized);
+        team_vesting.calculate_claimable_tokens(&market.team_vesting_schedule, current_time)?
+    };
 
     require!(claimable > 0, ErrorCode::InsufficientBalance);
 
@@ -89,20 +128,24 @@ pub fn handler(ctx: Context<ClaimTeamTokens>) -> Result<()> {
         signer_seeds,
     );
 
-    token_interface::transfer_checked(transfer_ctx, claimable, 6)?; // Pump.fun tokens use 6 decimals
+    token_interface::transfer_checked(transfer_ctx, claimable, ctx.accounts.token_mint.decimals)?;
 
     // -------------------------
     // 3) Update claimed amount
     // -------------------------
 
-    // Track if this claim includes immediate tokens
-    let includes_immediate = !team_vesting.immediate_claimed && team_vesting.immediate_tokens > 0;
+    // Track if this claim includes immediate tokens (single-wallet vesting only,
+    // split beneficiaries track their own totals on TeamSplit)
+    let includes_immediate = !market.team_split_enabled
+        && !team_vesting.immediate_claimed
+        && team_vesting.immediate_tokens > 0;
 
     // Mark immediate as claimed if applicable
     if includes_immediate {
         team_vesting.immediate_claimed = true;
     }
 
+    // Aggregate across all beneficiaries
     team_vesting.claimed_tokens = team_vesting
         .claimed_tokens
         .checked_add(claimable)
@@ -6,8 +6,10 @@ use crate::state::*;
 ///
 /// This instruction allows the founder to close their market account after:
 /// 1. Market has been resolved
-/// 2. Sufficient time has passed for all users to claim (e.g., 30 days after expiry)
-/// 3. Pool balance is zero (all rewards distributed)
+/// 2. Claim deadline has passed (set at resolution, see Treasury.claim_period)
+/// 3. Pool balance is zero (all rewards distributed, or swept via sweep_unclaimed)
+/// 4. YesWins: market token account closed via close_market_token_account
+/// 5. No unclaimed clawback SOL left in the account (claimed, or swept via sweep_unclaimed)
 ///
 /// This is an optional cleanup operation that:
 /// - Recovers the market PDA rent (~0.01 SOL) for the founder
@@ -16,7 +18,7 @@ use crate::state::*;
 ///
 /// Requirements:
 /// - Market must be resolved (not Unresolved)
-/// - Must be past claim period (expiry_time + 30 days)
+/// - Must be past the claim deadline
 /// - Pool balance must be 0 (or very small dust amount)
 /// - Only founder can close
 ///
@@ -40,16 +42,27 @@ pub fn handler(ctx: Context<CloseMarket>) -> Result<()> {
     let market = &ctx.accounts.market;
     let clock = Clock::get()?;
 
-    // Claim period: 30 days (2,592,000 seconds)
-    const CLAIM_PERIOD: i64 = 30 * 24 * 60 * 60;
-
-    // Check if claim period has passed
-    let claim_deadline = market.expiry_time + CLAIM_PERIOD;
+    // Claim deadline stamped at resolution (legacy markets: expiry + 30 days)
+    let claim_deadline = market.effective_claim_deadline();
     require!(
         clock.unix_timestamp > claim_deadline,
         ErrorCode::ClaimPeriodNotOver
     );
 
+    // YesWins markets own a token account; closing the market first would
+    // leave its tokens and rent with no authority able to move them
+    require!(
+        market.resolution != MarketResolution::YesWins || market.token_account_closed,
+        ErrorCode::TokenAccountNotClosed
+    );
+
+    // Clawed back SOL belongs to YES holders (or the treasury once swept),
+    // never to the founder it was taken from
+    require!(
+        market.clawback_unclaimed().1 == 0,
+        ErrorCode::AllocationsOutstanding
+    );
+
     // Check if pool is nearly empty
     // Allow small dust from rounding errors or unclaimed positions
     // Threshold: 0.01 SOL - enough for rounding but prevents closing with significant funds
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Close the market's token account after YesWins distribution completes
+///
+/// Allowed once every allocation held by the market token account is settled:
+/// 1. Platform 2% allocation claimed
+/// 2. YES voter allocation fully claimed, or unclaimed remainder swept
+/// 3. Team vesting fully claimed (or no team allocation when team_vesting was never initialized)
+/// 4. Clawed back tokens fully claimed, or the remainder swept (sweep_unclaimed)
+///
+/// Any rounding remainder left after that is moved to the treasury token
+/// account (never burned), then the account is closed via the token interface
+/// (market PDA signs) and its rent returned to the founder.
+/// Must run before close_market, otherwise token authority would be orphaned.
+///
+/// Callable by anyone (rent always goes to market.founder)
+#[derive(Accounts)]
+pub struct CloseMarketTokenAccount<'info> {
+    #[account(
+        mut,
+        constraint = market.resolution == MarketResolution::YesWins @ ErrorCode::InvalidResolutionState,
+        constraint = !market.token_account_closed @ ErrorCode::AlreadyInitialized
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Market's token account (closed by this instruction)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = market,
+        token::token_program = token_program
+    )]
+    pub market_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = Some(token_mint.key()) == market.token_mint @ ErrorCode::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Treasury's token account (receives any remainder)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = market.treasury,
+        token::token_program = token_program
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Team vesting account (must be fully claimed; omit if never initialized)
+    #[account(
+        seeds = [b"team_vesting", market.key().as_ref()],
+        bump = team_vesting.bump,
+        constraint = team_vesting.market == market.key() @ ErrorCode::Unauthorized
+    )]
+    pub team_vesting: Option<Account<'info, TeamVesting>>,
+
+    /// Market founder (receives the token account rent)
+    /// CHECK: Validated against market.founder
+    #[account(
+        mut,
+        constraint = founder.key() == market.founder @ ErrorCode::Unauthorized
+    )]
+    pub founder: UncheckedAccount<'info>,
+
+    pub caller: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<CloseMarketTokenAccount>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    // -------------------------
+    // 1) All allocations must be settled
+    // -------------------------
+
+    let platform_settled = market.platform_tokens_claimed || market.platform_tokens_allocated == 0;
+    let voters_settled = market.unclaimed_swept
+        || market.yes_voter_tokens_claimed >= market.yes_voter_tokens_allocated;
+    let team_settled = match ctx.accounts.team_vesting.as_ref() {
+        Some(team_vesting) => team_vesting.claimed_tokens >= team_vesting.total_tokens,
+        None => market.effective_team_tokens_allocated() == 0,
+    };
+    let clawback_settled = market.clawback_unclaimed().0 == 0;
+
+    require!(
+        platform_settled && voters_settled && team_settled && clawback_settled,
+        ErrorCode::AllocationsOutstanding
+    );
+
+    // Create PDA signer seeds for market account (token account authority)
+    let founder_key = market.founder;
+    let ipfs_hash = anchor_lang::solana_program::hash::hash(market.ipfs_cid.as_bytes());
+    let market_seeds = &[
+        b"market",
+        founder_key.as_ref(),
+        ipfs_hash.as_ref(),
+        &[market.bump],
+    ];
+    let signer_seeds = &[&market_seeds[..]];
+
+    // -------------------------
+    // 2) Move rounding remainder to the treasury (token accounts must be empty to close)
+    // -------------------------
+
+    let remainder = ctx.accounts.market_token_account.amount;
+    if remainder > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.market_token_account.to_account_info(),
+                    to: ctx.accounts.treasury_token_account.to_account_info(),
+                    authority: market.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            remainder,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+
+    // -------------------------
+    // 3) Close token account, rent → founder
+    // -------------------------
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.market_token_account.to_account_info(),
+            destination: ctx.accounts.founder.to_account_info(),
+            authority: market.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    market.token_account_closed = true;
+
+    msg!("🗑️  Market token account closed");
+    msg!("   Market: {}", market.key());
+    msg!("   Remainder to treasury: {}", remainder);
+
+    Ok(())
+}
@@ -15,6 +15,14 @@ use crate::state::*;
 /// - Position must be claimed already (rewards already received)
 ///   OR market must be in Refund state
 ///
+/// YesWins: closing the position forfeits any share of a later clawback
+/// (see vote_clawback / claim_clawback). While founder milestones gate excess
+/// SOL, pass founder_milestones: the position stays open until every milestone
+/// is attested (its YES shares count toward the attest_milestone majority).
+///
+/// Refund positions closed without claiming are recorded in the user's
+/// Reputation as a refund of 0, so skipping the claim does not hide the outcome.
+///
 /// Result: Position PDA closed, rent refunded to user
 #[derive(Accounts)]
 pub struct ClosePosition<'info> {
@@ -32,9 +40,28 @@ pub struct ClosePosition<'info> {
     )]
     pub position: Account<'info, Position>,
 
+    /// User's predictor track record (created on first settlement)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = Reputation::SPACE,
+        seeds = [b"reputation", user.key().as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+
     /// User closing the position
     #[account(mut)]
     pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Founder milestones (required for YesWins markets with milestones enabled)
+    #[account(
+        seeds = [b"founder_milestones", market.key().as_ref()],
+        bump = founder_milestones.bump
+    )]
+    pub founder_milestones: Option<Account<'info, FounderMilestones>>,
 }
 
 pub fn handler(ctx: Context<ClosePosition>) -> Result<()> {
@@ -45,11 +72,48 @@ pub fn handler(ctx: Context<ClosePosition>) -> Result<()> {
     // Allow closing if:
     // 1. Position already claimed (rewards received)
     // 2. OR market is in Refund state (can close without claiming)
+    // 3. OR market is resolved with outcome tokens (claims are paid on tokens, position is only an index)
+    let token_market_resolved = market.outcome_tokens_enabled
+        && market.resolution != MarketResolution::Unresolved;
     require!(
-        position.claimed || market.resolution == MarketResolution::Refund,
+        position.claimed || market.resolution == MarketResolution::Refund || token_market_resolved,
         ErrorCode::CannotClosePosition
     );
 
+    // -------------------------
+    // Keep YES voting weight while founder milestones are unattested
+    // -------------------------
+
+    if market.resolution == MarketResolution::YesWins
+        && market.founder_milestones_enabled
+        && market.founder_excess_sol_allocated > 0
+        && market.founder_vesting_revoked_at == 0
+    {
+        let founder_milestones = ctx
+            .accounts
+            .founder_milestones
+            .as_ref()
+            .ok_or(ErrorCode::InvalidMilestones)?;
+        require!(founder_milestones.all_attested(), ErrorCode::MilestonesPending);
+    }
+
+    // -------------------------
+    // Record unclaimed refunds in the user's reputation
+    // -------------------------
+
+    let reputation = &mut ctx.accounts.reputation;
+    if reputation.wallet == Pubkey::default() {
+        reputation.wallet = ctx.accounts.user.key();
+        reputation.bump = ctx.bumps.reputation;
+    }
+
+    if !position.claimed
+        && market.resolution == MarketResolution::Refund
+        && !market.outcome_tokens_enabled
+    {
+        reputation.record_settlement(SettlementOutcome::Refund, position.total_invested, 0)?;
+    }
+
     msg!("🗑️  Closing position account");
     msg!("   User: {}", ctx.accounts.user.key());
     msg!("   Market: {}", market.key());
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Set the founder verifier role and verification threshold (admin only)
+///
+/// - verifier: wallet allowed to call verify_founder besides the admin (Pubkey::default() = admin only)
+/// - min_target_pool: create_market needs a verified FounderProfile for target
+///   pools at or above this (lamports, 0 = verification never required)
+#[derive(Accounts)]
+pub struct ConfigureFounderVerification<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub admin: Signer<'info>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureFounderVerification>,
+    verifier: Pubkey,
+    min_target_pool: u64,
+) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::ConfigureFounderVerification { verifier, min_target_pool },
+    )?;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.founder_verifier = verifier;
+    treasury.verification_min_target = min_target_pool;
+
+    msg!("🪪 Founder verification configured");
+    msg!("   Verifier: {}", verifier);
+    msg!("   Required from target pool: {} lamports (0 = never)", min_target_pool);
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::constants::{BPS_DIVISOR, MAX_FOUNDER_MILESTONES};
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Gate part of the founder's vested excess SOL behind milestones
+///
+/// Only callable by founder before the first trade
+/// (send in the same transaction as create_market), so voters know which
+/// part of the founder's SOL depends on delivery.
+///
+/// Each entry is the share (bps) of the 92% vested slice released only after
+/// that milestone is attested via attest_milestone. Shares may total less than
+/// 100%; the remainder vests on time alone. Passing an empty list disables milestones.
+#[derive(Accounts)]
+pub struct ConfigureMilestones<'info> {
+    #[account(
+        mut,
+        constraint = market.founder == founder.key() @ ErrorCode::Unauthorized,
+        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        init_if_needed,
+        payer = founder,
+        space = FounderMilestones::SPACE,
+        seeds = [b"founder_milestones", market.key().as_ref()],
+        bump
+    )]
+    pub founder_milestones: Account<'info, FounderMilestones>,
+
+    /// Market founder (only they can configure the market, pays for the milestones account)
+    #[account(mut)]
+    pub founder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ConfigureMilestones>, milestone_bps: Vec<u16>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let founder_milestones = &mut ctx.accounts.founder_milestones;
+
+    // -------------------------
+    // 1) Configuration is frozen once trading starts
+    // -------------------------
+
+    require!(
+        market.pool_balance == 0
+            && market.total_yes_shares == 0
+            && market.total_no_shares == 0,
+        ErrorCode::MarketAlreadyTrading
+    );
+
+    // -------------------------
+    // 2) Validate milestones (empty list disables gating)
+    // -------------------------
+
+    require!(
+        milestone_bps.len() <= MAX_FOUNDER_MILESTONES,
+        ErrorCode::InvalidMilestones
+    );
+    require!(
+        milestone_bps.iter().all(|&bps| bps > 0),
+        ErrorCode::InvalidMilestones
+    );
+
+    let total_bps: u64 = milestone_bps.iter().map(|&bps| bps as u64).sum();
+    require!(total_bps <= BPS_DIVISOR, ErrorCode::InvalidMilestones);
+
+    // -------------------------
+    // 3) Store milestones (reviewer is appointed later by the admin)
+    // -------------------------
+
+    founder_milestones.market = market.key();
+    founder_milestones.milestones = milestone_bps
+        .iter()
+        .map(|&bps| Milestone {
+            bps,
+            attested: false,
+            attested_at: 0,
+            votes_for: 0,
+        })
+        .collect();
+    founder_milestones.bump = ctx.bumps.founder_milestones;
+
+    market.founder_milestones_enabled = !founder_milestones.milestones.is_empty();
+
+    msg!("🏁 Founder milestones configured");
+    msg!("   Milestones: {}", founder_milestones.milestones.len());
+    msg!("   Gated share of vested SOL: {} bps", total_bps);
+
+    Ok(())
+}
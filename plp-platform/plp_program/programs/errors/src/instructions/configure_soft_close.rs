@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Configure the anti-sniping soft-close rule for a market
+///
+/// Only callable by founder before the first trade
+/// (send in the same transaction as create_market).
+///
+/// Once configured, any buy within the final `window` seconds before
+/// expiry_time pushes expiry_time out by `extension` seconds, never past
+/// `hard_expiry_time`. Passing window = 0 disables the rule again.
+#[derive(Accounts)]
+pub struct ConfigureSoftClose<'info> {
+    #[account(
+        mut,
+        constraint = market.founder == founder.key() @ ErrorCode::Unauthorized,
+        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Market founder (only they can configure the market)
+    pub founder: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureSoftClose>,
+    window: i64,
+    extension: i64,
+    hard_expiry_time: i64,
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    // -------------------------
+    // 1) Configuration is frozen once trading starts
+    // -------------------------
+
+    require!(
+        market.pool_balance == 0
+            && market.total_yes_shares == 0
+            && market.total_no_shares == 0,
+        ErrorCode::MarketAlreadyTrading
+    );
+
+    // -------------------------
+    // 2) Disable soft close
+    // -------------------------
+
+    if window == 0 {
+        market.soft_close_window = 0;
+        market.soft_close_extension = 0;
+        market.hard_expiry_time = 0;
+        return Ok(());
+    }
+
+    // -------------------------
+    // 3) Validate and store soft-close rule
+    // -------------------------
+
+    require!(
+        window > 0 && window <= MAX_SOFT_CLOSE_WINDOW,
+        ErrorCode::InvalidSoftCloseConfig
+    );
+    require!(
+        extension > 0 && extension <= MAX_SOFT_CLOSE_EXTENSION,
+        ErrorCode::InvalidSoftCloseConfig
+    );
+
+    // Hard end time must be after expiry and within the maximum market duration
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        hard_expiry_time > market.expiry_time,
+        ErrorCode::InvalidSoftCloseConfig
+    );
+    require!(
+        hard_expiry_time - now <= MAX_MARKET_DURATION,
+        ErrorCode::InvalidSoftCloseConfig
+    );
+
+    market.soft_close_window = window;
+    market.soft_close_extension = extension;
+    market.hard_expiry_time = hard_expiry_time;
+
+    msg!("⏱️  Soft close configured");
+    msg!("   Window: {} seconds", window);
+    msg!("   Extension: {} seconds per late trade", extension);
+    msg!("   Hard expiry: {}", hard_expiry_time);
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::constants::{BPS_DIVISOR, MAX_TEAM_BENEFICIARIES};
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Beneficiary entry passed to configure_team_split
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct TeamSplitEntry {
+    pub wallet: Pubkey,
+    pub bps: u16,
+}
+
+/// Split the team token allocation across several beneficiaries
+///
+/// Only callable by founder before the first trade
+/// (send in the same transaction as create_market), so voters see who
+/// receives the 33% team allocation before buying.
+///
+/// Each beneficiary then claims their own share via claim_team_tokens,
+/// following the market's team vesting schedule. Passing an empty list
+/// disables the split (whole allocation vests to team_wallet again).
+#[derive(Accounts)]
+pub struct ConfigureTeamSplit<'info> {
+    #[account(
+        mut,
+        constraint = market.founder == founder.key() @ ErrorCode::Unauthorized,
+        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        init_if_needed,
+        payer = founder,
+        space = TeamSplit::SPACE,
+        seeds = [b"team_split", market.key().as_ref()],
+        bump
+    )]
+    pub team_split: Account<'info, TeamSplit>,
+
+    /// Market founder (only they can configure the market, pays for the split account)
+    #[account(mut)]
+    pub founder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ConfigureTeamSplit>, beneficiaries: Vec<TeamSplitEntry>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let team_split = &mut ctx.accounts.team_split;
+
+    // -------------------------
+    // 1) Configuration is frozen once trading starts
+    // -------------------------
+
+    require!(
+        market.pool_balance == 0
+            && market.total_yes_shares == 0
+            && market.total_no_shares == 0,
+        ErrorCode::MarketAlreadyTrading
+    );
+
+    // -------------------------
+    // 2) Validate beneficiaries (empty list disables the split)
+    // -------------------------
+
+    require!(
+        beneficiaries.len() <= MAX_TEAM_BENEFICIARIES,
+        ErrorCode::InvalidTeamSplit
+    );
+
+    let mut total_bps: u64 = 0;
+    for (i, entry) in beneficiaries.iter().enumerate() {
+        require!(entry.bps > 0, ErrorCode::InvalidTeamSplit);
+        require!(
+            beneficiaries[..i].iter().all(|other| other.wallet != entry.wallet),
+            ErrorCode::InvalidTeamSplit
+        );
+        total_bps += entry.bps as u64;
+    }
+
+    require!(
+        beneficiaries.is_empty() || total_bps == BPS_DIVISOR,
+        ErrorCode::InvalidTeamSplit
+    );
+
+    // -------------------------
+    // 3) Store split
+    // -------------------------
+
+    team_split.market = market.key();
+    team_split.beneficiaries = beneficiaries
+        .iter()
+        .map(|entry| TeamBeneficiary {
+            wallet: entry.wallet,
+            bps: entry.bps,
+            claimed_tokens: 0,
+        })
+        .collect();
+    team_split.bump = ctx.bumps.team_split;
+
+    market.team_split_enabled = !team_split.beneficiaries.is_empty();
+
+    msg!("👥 Team split configured");
+    for beneficiary in team_split.beneficiaries.iter() {
+        msg!("   {}: {} bps", beneficiary.wallet, beneficiary.bps);
+    }
+
+    Ok(())
+}
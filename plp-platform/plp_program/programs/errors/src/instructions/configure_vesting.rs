@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_VESTING_DURATION, MIN_VESTING_DURATION};
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Configure team token and founder SOL vesting schedules for a market
+///
+/// The schedules are first set by the founder at create_market; this
+/// instruction adjusts them and sets the options below. Only callable by the
+/// founder before the first trade, so voters know the unlock terms before buying.
+///
+/// Each schedule picks a curve (Linear / Cliff / MonthlyStep), an optional
+/// cliff and a duration within platform bounds. Passing an all-zero schedule
+/// restores the default (12 months linear, no cliff).
+///
+/// `voter_vesting_duration` streams YES voter tokens linearly from resolution
+/// instead of paying them at once (0 = disabled; not available with outcome tokens).
+///
+/// `revocable` places the market under platform governance: the admin may
+/// later call revoke_vesting to stop unlocks and reclaim unvested amounts.
+#[derive(Accounts)]
+pub struct ConfigureVesting<'info> {
+    #[account(
+        mut,
+        constraint = market.founder == founder.key() @ ErrorCode::Unauthorized,
+        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Market founder (only they can configure the market)
+    pub founder: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureVesting>,
+    team_schedule: VestingSchedule,
+    founder_schedule: VestingSchedule,
+    revocable: bool,
+    voter_vesting_duration: i64,
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    // -------------------------
+    // 1) Configuration is frozen once trading starts
+    // -------------------------
+
+    require!(
+        market.pool_balance == 0
+            && market.total_yes_shares == 0
+            && market.total_no_shares == 0,
+        ErrorCode::MarketAlreadyTrading
+    );
+
+    // -------------------------
+    // 2) Validate and store schedules
+    // -------------------------
+
+    require!(team_schedule.is_valid(), ErrorCode::InvalidVestingSchedule);
+    require!(founder_schedule.is_valid(), ErrorCode::InvalidVestingSchedule);
+    require!(
+        voter_vesting_duration == 0
+            || (MIN_VESTING_DURATION..=MAX_VESTING_DURATION).contains(&voter_vesting_duration),
+        ErrorCode::InvalidVestingSchedule
+    );
+    require!(
+        voter_vesting_duration == 0 || !market.outcome_tokens_enabled,
+        ErrorCode::VoterVestingUnsupported
+    );
+
+    market.team_vesting_schedule = team_schedule;
+    market.founder_vesting_schedule = founder_schedule;
+    market.vesting_revocable = revocable;
+    market.voter_vesting_duration = voter_vesting_duration;
+
+    let team = team_schedule.effective();
+    let founder = founder_schedule.effective();
+
+    msg!("📅 Vesting configured");
+    msg!(
+        "   Team: {:?}, cliff {} s, duration {} s",
+        team.curve,
+        team.cliff_seconds,
+        team.duration_seconds
+    );
+    msg!(
+        "   Founder: {:?}, cliff {} s, duration {} s",
+        founder.curve,
+        founder.cliff_seconds,
+        founder.duration_seconds
+    );
+    msg!("   Revocable by admin: {}", revocable);
+    msg!("   Voter vesting: {} s (0 = none)", voter_vesting_duration);
+
+    Ok(())
+}
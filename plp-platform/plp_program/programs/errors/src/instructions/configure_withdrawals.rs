@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_WITHDRAWAL_DELAY, MAX_WITHDRAWAL_RECIPIENTS, MIN_WITHDRAWAL_DELAY};
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Set the treasury withdrawal policy (admin only)
+///
+/// - withdrawal_delay: timelock between queue_withdrawal and execute_withdrawal (1 hour - 30 days)
+/// - epoch_cap: max lamports executed per Solana epoch (0 = no cap)
+/// - allowlist: the only wallets withdrawals can be queued to (max 5, replaces the current list)
+///
+/// Already queued withdrawals keep their executable_at, but must still pass the
+/// cap and allowlist when executed.
+#[derive(Accounts)]
+pub struct ConfigureWithdrawals<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub admin: Signer<'info>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureWithdrawals>,
+    withdrawal_delay: i64,
+    epoch_cap: u64,
+    allowlist: Vec<Pubkey>,
+) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::ConfigureWithdrawals {
+            withdrawal_delay,
+            epoch_cap,
+            allowlist: allowlist.clone(),
+        },
+    )?;
+
+    let treasury = &mut ctx.accounts.treasury;
+
+    require!(
+        (MIN_WITHDRAWAL_DELAY..=MAX_WITHDRAWAL_DELAY).contains(&withdrawal_delay),
+        ErrorCode::InvalidWithdrawalConfig
+    );
+    require!(
+        allowlist.len() <= MAX_WITHDRAWAL_RECIPIENTS,
+        ErrorCode::InvalidWithdrawalConfig
+    );
+
+    // No default/treasury keys, no duplicates
+    let treasury_key = treasury.key();
+    for (i, recipient) in allowlist.iter().enumerate() {
+        require!(
+            *recipient != Pubkey::default() && *recipient != treasury_key,
+            ErrorCode::InvalidWithdrawalConfig
+        );
+        require!(
+            !allowlist[..i].contains(recipient),
+            ErrorCode::InvalidWithdrawalConfig
+        );
+    }
+
+    treasury.withdrawal_delay = withdrawal_delay;
+    treasury.withdrawal_epoch_cap = epoch_cap;
+    treasury.set_withdrawal_allowlist(&allowlist);
+
+    msg!("🏦 Withdrawal policy updated");
+    msg!("   Delay: {} seconds", withdrawal_delay);
+    msg!("   Epoch cap: {} lamports", epoch_cap);
+    msg!("   Allowlisted recipients: {}", treasury.withdrawal_allowlist().len());
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::constants::{BPS_DIVISOR, TRADE_FEE_BPS};
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Batch-process claims for many positions in one transaction (permissionless)
+///
+/// Used by operators to sweep NoWins / Refund markets so SOL is not stranded
+/// by dead wallets and close_market is no longer blocked on PoolNotEmpty,
+/// and to settle losing positions so every outcome reaches the owner's record.
+///
+/// remaining_accounts: triples of (position, owner wallet, owner Reputation PDA), all writable
+/// - NoWins: NO holders receive their proportional share of distribution_pool
+/// - Refund: every holder receives total_invested minus trading fees (98.5%)
+/// - Positions with nothing owed (YES holders after NoWins, NO holders after
+///   YesWins) are just closed and recorded as losses
+/// - YesWins winners are rejected (they claim tokens via claim_rewards)
+///
+/// Payouts and position rent always go to position.user, never the caller.
+/// Each settled position is recorded in the owner's Reputation, which the
+/// caller creates (and pays rent for) if it does not exist yet.
+/// Outcome token markets are excluded: payouts follow tokens, not positions.
+#[derive(Accounts)]
+pub struct CrankClaims<'info> {
+    #[account(
+        mut,
+        constraint = market.resolution != MarketResolution::Unresolved @ ErrorCode::InvalidResolutionState,
+        constraint = !market.outcome_tokens_enabled @ ErrorCode::OutcomeTokensEnabled,
+        constraint = !market.unclaimed_swept @ ErrorCode::ClaimPeriodOver
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Anyone can crank claims (funds always go to position owners)
+    /// Pays rent for Reputation accounts created here
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Load the owner's Reputation from `info`, creating the PDA if needed
+fn load_or_create_reputation<'info>(
+    info: &AccountInfo<'info>,
+    owner: &Pubkey,
+    caller: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+) -> Result<Reputation> {
+    let (expected, bump) =
+        Pubkey::find_program_address(&[b"reputation", owner.as_ref()], program_id);
+    require!(info.key() == expected, ErrorCode::InvalidAccountData);
+
+    if info.owner == program_id {
+        // try_deserialize checks the Reputation discriminator
+        return Reputation::try_deserialize(&mut &info.try_borrow_data()?[..]);
+    }
+
+    let seeds = &[b"reputation".as_ref(), owner.as_ref(), &[bump]];
+    let lamports = Rent::get()?.minimum_balance(Reputation::SPACE);
+
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::CreateAccount {
+                from: caller.clone(),
+                to: info.clone(),
+            },
+            &[&seeds[..]],
+        ),
+        lamports,
+        Reputation::SPACE as u64,
+        program_id,
+    )?;
+
+    Ok(Reputation::new(*owner, bump))
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CrankClaims<'info>>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let market_key = market.key();
+    let remaining = ctx.remaining_accounts;
+
+    let triples = remaining.chunks_exact(3);
+    require!(
+        !remaining.is_empty() && triples.remainder().is_empty(),
+        ErrorCode::InvalidAccountData
+    );
+
+    let caller_info = ctx.accounts.caller.to_account_info();
+    let system_program_info = ctx.accounts.system_program.to_account_info();
+
+    let mut total_paid: u64 = 0;
+    let mut processed: u32 = 0;
+
+    for triple in triples {
+        let position_info = &triple[0];
+        let owner_info = &triple[1];
+        let reputation_info = &triple[2];
+
+        // Account::try_from checks program ownership and the Position discriminator,
+        // so a closed or already-processed position aborts the whole batch
+        let position: Account<'info, Position> = Account::try_from(position_info)?;
+
+        require!(position.market == market_key, ErrorCode::Unauthorized);
+        require!(position.user == owner_info.key(), ErrorCode::Unauthorized);
+        require!(!position.claimed, ErrorCode::AlreadyClaimed);
+
+        // -------------------------
+        // Calculate payout (same math as claim_rewards)
+        // -------------------------
+
+        let (payout, outcome) = match market.resolution {
+            MarketResolution::NoWins => {
+                if position.no_shares > 0 && market.total_no_shares > 0 {
+                    // payout = (user_no_shares / total_no_shares) * distribution_pool
+                    let payout = ((position.no_shares as u128 * market.distribution_pool as u128)
+                        / market.total_no_shares as u128) as u64;
+                    (payout, SettlementOutcome::Win)
+                } else {
+                    (0, SettlementOutcome::Loss)
+                }
+            }
+            MarketResolution::Refund => {
+                // refund_amount = total_invested * (10000 - 150) / 10000 = 98.5% of invested
+                let refund = (position.total_invested as u128 * (BPS_DIVISOR - TRADE_FEE_BPS) as u128
+                    / BPS_DIVISOR as u128) as u64;
+                (refund, SettlementOutcome::Refund)
+            }
+            MarketResolution::YesWins => {
+                // Only losing positions; YES holders claim their tokens themselves
+                require!(position.yes_shares == 0, ErrorCode::InvalidResolutionState);
+                (0, SettlementOutcome::Loss)
+            }
+            MarketResolution::Unresolved => return Err(ErrorCode::InvalidResolutionState.into()),
+        };
+
+        // -------------------------
+        // Pay owner from market account
+        // -------------------------
+
+        if payout > 0 {
+            let market_balance = market.to_account_info().lamports();
+            require!(payout <= market_balance, ErrorCode::InsufficientBalance);
+
+            **market.to_account_info().try_borrow_mut_lamports()? -= payout;
+            **owner_info.try_borrow_mut_lamports()? += payout;
+
+            market.pool_balance = market
+                .pool_balance
+                .checked_sub(payout)
+                .ok_or(ErrorCode::MathError)?;
+
+            total_paid = total_paid
+                .checked_add(payout)
+                .ok_or(ErrorCode::MathError)?;
+        }
+
+        // -------------------------
+        // Record the outcome in the owner's reputation
+        // -------------------------
+
+        let mut reputation = load_or_create_reputation(
+            reputation_info,
+            &position.user,
+            &caller_info,
+            &system_program_info,
+            ctx.program_id,
+        )?;
+        reputation.record_settlement(outcome, position.total_invested, payout)?;
+        reputation.try_serialize(&mut &mut reputation_info.try_borrow_mut_data()?[..])?;
+
+        // -------------------------
+        // 🔥 RENT RECOVERY: close position, rent goes to owner
+        // -------------------------
+
+        position.close(owner_info.clone())?;
+        processed += 1;
+    }
+
+    msg!("🧹 Cranked {} claims", processed);
+    msg!("   Market: {}", market_key);
+    msg!("   Total paid: {} lamports", total_paid);
+    msg!("   Remaining pool: {} lamports", market.pool_balance);
+
+    Ok(())
+}
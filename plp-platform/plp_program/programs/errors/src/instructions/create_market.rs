@@ -12,7 +12,8 @@ use crate::state::*;
 /// - Market Vault PDA = seeds: ["market_vault", market]
 ///   (Pure SOL holder, 0 bytes data, used for all SOL transfers)
 ///
-/// Charges 0.015 SOL creation fee to treasury
+/// Charges 0.015 SOL creation fee to treasury and locks a 1% founder bond
+/// Stores the founder's team token / founder SOL vesting schedules (all-zero = default)
 /// Initializes Constant Product AMM with equal pools (yes_pool = no_pool = target_pool)
 /// This starts the market at 50/50 price (0.5 probability for each side)
 #[derive(Accounts)]
@@ -39,7 +40,8 @@ pub struct CreateMarket<'info> {
     #[account(
         mut,
         seeds = [b"treasury"],
-        bump = treasury.bump
+        bump = treasury.bump,
+        constraint = !treasury.is_paused(PAUSE_CREATE_MARKET) @ ErrorCode::InstructionPaused
     )]
     pub treasury: Account<'info, Treasury>,
 
@@ -47,7 +49,26 @@ pub struct CreateMarket<'info> {
     #[account(mut)]
     pub founder: Signer<'info>,
 
+    /// Founder creation bond (1% of target_pool, see FounderBond)
+    #[account(
+        init,
+        payer = founder,
+        space = FounderBond::SPACE,
+        seeds = [b"founder_bond", market.key().as_ref()],
+        bump
+    )]
+    pub founder_bond: Account<'info, FounderBond>,
+
     pub system_program: Program<'info, System>,
+
+    /// Founder's profile (required when the treasury demands verification for
+    /// this target pool; counts the founder's markets when passed)
+    #[account(
+        mut,
+        seeds = [b"founder_profile", founder.key().as_ref()],
+        bump = founder_profile.bump
+    )]
+    pub founder_profile: Option<Account<'info, FounderProfile>>,
 }
 
 pub fn handler(
@@ -56,6 +77,8 @@ pub fn handler(
     target_pool: u64,
     expiry_time: i64,
     metadata_uri: String,
+    team_schedule: VestingSchedule,
+    founder_schedule: VestingSchedule,
 ) -> Result<()> {
     // Get market key before mutable borrow
     let market_key = ctx.accounts.market.key();
@@ -74,6 +97,17 @@ pub fn handler(
         ErrorCode::InvalidTargetPool
     );
 
+    // Large markets may require a verified founder (Treasury.verification_min_target)
+    if ctx.accounts.treasury.requires_verified_founder(target_pool) {
+        require!(
+            ctx.accounts
+                .founder_profile
+                .as_ref()
+                .is_some_and(|profile| profile.verified),
+            ErrorCode::FounderNotVerified
+        );
+    }
+
     // Validate IPFS CID length
     require!(
         ipfs_cid.len() <= MAX_IPFS_CID_LEN,
@@ -100,6 +134,10 @@ pub fn handler(
         ErrorCode::InvalidMarketPhase
     );
 
+    // Vesting schedules must be within platform bounds
+    require!(team_schedule.is_valid(), ErrorCode::InvalidVestingSchedule);
+    require!(founder_schedule.is_valid(), ErrorCode::InvalidVestingSchedule);
+
     // -------------------------
     // 2) Transfer creation fee to treasury
     // -------------------------
@@ -117,13 +155,41 @@ pub fn handler(
         CREATION_FEE_LAMPORTS,
     )?;
 
-    // Update treasury total fees
-    ctx.accounts.treasury.total_fees = ctx
-        .accounts
+    // Update treasury fee ledger
+    ctx.accounts
         .treasury
-        .total_fees
-        .checked_add(CREATION_FEE_LAMPORTS)
-        .ok_or(ErrorCode::MathError)?;
+        .record_fee(FeeSource::Creation, CREATION_FEE_LAMPORTS)?;
+
+    // -------------------------
+    // 2b) Lock founder bond (refunded via release_founder_bond)
+    // -------------------------
+
+    let bond_amount = FounderBond::required_amount(target_pool);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.founder.to_account_info(),
+                to: ctx.accounts.founder_bond.to_account_info(),
+            },
+        ),
+        bond_amount,
+    )?;
+
+    let founder_bond = &mut ctx.accounts.founder_bond;
+    founder_bond.market = market_key;
+    founder_bond.founder = ctx.accounts.founder.key();
+    founder_bond.amount = bond_amount;
+    founder_bond.locked_at = now;
+    founder_bond.bump = ctx.bumps.founder_bond;
+
+    if let Some(founder_profile) = ctx.accounts.founder_profile.as_mut() {
+        founder_profile.markets_created = founder_profile
+            .markets_created
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+    }
 
     // -------------------------
     // 3) Initialize market data
@@ -157,6 +223,44 @@ pub fn handler(
     market.treasury = ctx.accounts.treasury.key();
     market.bump = ctx.bumps.market;
 
+    // Soft close disabled by default (see configure_soft_close)
+    market.soft_close_window = 0;
+    market.soft_close_extension = 0;
+    market.hard_expiry_time = 0;
+
+    // Position-only shares by default (see enable_outcome_tokens)
+    market.outcome_tokens_enabled = false;
+    market.yes_net_invested = 0;
+    market.no_net_invested = 0;
+
+    // Claim window is stamped at resolution
+    market.claim_deadline = 0;
+    market.yes_voter_tokens_claimed = 0;
+    market.unclaimed_swept = false;
+    market.swept_sol = 0;
+    market.swept_tokens = 0;
+    market.token_account_closed = false;
+    market.team_tokens_allocated = 0;
+    market.team_vesting_schedule = team_schedule;
+    market.founder_vesting_schedule = founder_schedule;
+    market.team_split_enabled = false;
+    market.vesting_revocable = false;
+    market.team_vesting_revoked_at = 0;
+    market.founder_vesting_revoked_at = 0;
+    market.voter_vesting_duration = 0;
+    market.resolved_at = 0;
+    market.founder_milestones_enabled = false;
+    market.clawback_votes = 0;
+    market.clawback_executed_at = 0;
+    market.clawback_tokens = 0;
+    market.clawback_sol = 0;
+    market.clawback_tokens_claimed = 0;
+    market.clawback_sol_claimed = 0;
+    market.paused = 0;
+    market.creation_fee_paid = CREATION_FEE_LAMPORTS;
+    market.trade_fees_generated = 0;
+    market.completion_fee_paid = 0;
+
     // -------------------------
     // 4) Initialize Market Vault PDA
     // -------------------------
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Move the stakers' share of new treasury fees into the staking pool (permissionless)
+///
+/// new fees = Treasury.total_fees - staking_pool.fees_checkpoint
+/// reward   = new fees * staker_share_bps / 10000
+///
+/// The reward is spread over everything staked right now. If nothing is staked
+/// the fees stay in the treasury. Capped at the treasury balance above its
+/// rent-exempt minimum; when capped, the checkpoint only advances over the fees
+/// actually paid so the rest is distributed once the treasury is topped up.
+#[derive(Accounts)]
+pub struct DistributeStakingRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_pool"],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// Anyone can crank distributions (SOL only moves treasury → staking pool)
+    pub caller: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<DistributeStakingRewards>) -> Result<()> {
+    let treasury = &ctx.accounts.treasury;
+    let staking_pool = &mut ctx.accounts.staking_pool;
+
+    // -------------------------
+    // 1) Stakers' share of fees since the last distribution
+    // -------------------------
+
+    let new_fees = treasury.total_fees.saturating_sub(staking_pool.fees_checkpoint);
+    let owed = staking_pool.undistributed_reward(treasury.total_fees);
+    let mut reward = owed;
+
+    // -------------------------
+    // 2) Transfer lamports (treasury stays rent-exempt)
+    // -------------------------
+
+    if reward > 0 {
+        let treasury_info = treasury.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(treasury_info.data_len());
+        let available = treasury_info.lamports().saturating_sub(rent_exempt);
+        reward = std::cmp::min(reward, available);
+
+        **treasury_info.try_borrow_mut_lamports()? -= reward;
+        **staking_pool.to_account_info().try_borrow_mut_lamports()? += reward;
+    }
+
+    // -------------------------
+    // 3) Update accumulator
+    // -------------------------
+
+    staking_pool.accrue(reward)?;
+    staking_pool.advance_checkpoint(treasury.total_fees, new_fees, owed, reward);
+
+    msg!("🥩 Staking rewards distributed");
+    msg!("   New fees: {} lamports", new_fees);
+    msg!("   Reward: {} of {} lamports over {} staked", reward, owed, staking_pool.total_staked);
+    msg!("   Reward per share: {}", staking_pool.reward_per_share);
+
+    Ok(())
+}
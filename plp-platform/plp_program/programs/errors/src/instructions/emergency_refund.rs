@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::constants::MAX_EMERGENCY_REASON_LEN;
+use crate::errors::ErrorCode;
+use crate::events::EmergencyRefundEvent;
+use crate::state::*;
+
+/// Force an unresolved market into Refund (platform admin only)
+///
+/// Used during incidents instead of draining funds to a single wallet:
+/// 1. Moves all vault SOL (minus rent-exempt) into the market account
+/// 2. Sets resolution = Refund and stamps the claim deadline
+/// 3. Emits EmergencyRefundEvent with the admin's reason
+///
+/// Every position then reclaims total_invested less trading fees through
+/// claim_rewards (or crank_claims), exactly like a normal Refund.
+/// Works with both program-owned (legacy) and system-owned vaults.
+#[derive(Accounts)]
+pub struct EmergencyRefund<'info> {
+    #[account(
+        mut,
+        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved,
+        constraint = market.treasury == treasury.key() @ ErrorCode::Unauthorized
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Market Vault PDA (can be program-owned or system-owned)
+    /// CHECK: Validated via PDA derivation
+    #[account(
+        mut,
+        seeds = [b"market_vault", market.key().as_ref()],
+        bump
+    )]
+    pub market_vault: UncheckedAccount<'info>,
+
+    /// Global Treasury PDA (admin authorization, claim period)
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Platform admin
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<EmergencyRefund>, reason: String) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::EmergencyRefund {
+            market: ctx.accounts.market.key(),
+            reason: reason.clone(),
+        },
+    )?;
+
+    require!(
+        !reason.is_empty() && reason.len() <= MAX_EMERGENCY_REASON_LEN,
+        ErrorCode::InvalidEmergencyReason
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    // -------------------------
+    // 1) Move vault SOL into the market account
+    // -------------------------
+
+    // Keep minimum rent-exempt balance in vault (same as a normal Refund resolution)
+    let rent = Rent::get()?;
+    let vault_rent_exempt = rent.minimum_balance(0);
+    let refund_pool = ctx
+        .accounts
+        .market_vault
+        .lamports()
+        .saturating_sub(vault_rent_exempt);
+
+    let vault_owner = ctx.accounts.market_vault.owner;
+    require!(
+        vault_owner == ctx.program_id || vault_owner == &System::id(),
+        ErrorCode::Unauthorized
+    );
+
+    if refund_pool > 0 {
+        if vault_owner == ctx.program_id {
+            // Program-owned vault: direct lamport manipulation
+            **ctx.accounts.market_vault.try_borrow_mut_lamports()? -= refund_pool;
+            **ctx.accounts.market.to_account_info().try_borrow_mut_lamports()? += refund_pool;
+        } else {
+            // System-owned vault: transfer signed by the vault PDA
+            let market_key = ctx.accounts.market.key();
+            let vault_seeds = &[
+                b"market_vault",
+                market_key.as_ref(),
+                &[ctx.bumps.market_vault],
+            ];
+            let signer_seeds = &[&vault_seeds[..]];
+
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.market_vault.to_account_info(),
+                        to: ctx.accounts.market.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                refund_pool,
+            )?;
+        }
+    }
+
+    // -------------------------
+    // 2) Force Refund resolution
+    // -------------------------
+
+    let market = &mut ctx.accounts.market;
+    market.pool_balance = refund_pool;
+    market.resolution = MarketResolution::Refund;
+    market.resolved_at = now;
+    market.claim_deadline = now
+        .checked_add(ctx.accounts.treasury.effective_claim_period())
+        .ok_or(ErrorCode::MathError)?;
+
+    // -------------------------
+    // 3) Explain why
+    // -------------------------
+
+    msg!("🚨 Emergency refund");
+    msg!("   Market: {}", market.key());
+    msg!("   Refund pool: {} lamports", refund_pool);
+    msg!("   Reason: {}", reason);
+
+    emit!(EmergencyRefundEvent {
+        market: market.key(),
+        admin: ctx.accounts.admin.key(),
+        reason,
+        refund_pool,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Enable outcome tokens for a market (opt-in)
+///
+/// Only callable by founder before the first trade
+/// (send in the same transaction as create_market).
+///
+/// PDA layout:
+/// - YES Mint PDA = seeds: ["yes_mint", market]
+/// - NO Mint PDA = seeds: ["no_mint", market]
+///   (mint authority = market PDA, decimals = 9 to match share scaling)
+///
+/// Once enabled, buy_yes/buy_no mint outcome tokens 1:1 with shares and
+/// claim_rewards burns them for payout, so shares can be traded as SPL tokens.
+#[derive(Accounts)]
+pub struct EnableOutcomeTokens<'info> {
+    #[account(
+        mut,
+        constraint = market.founder == founder.key() @ ErrorCode::Unauthorized,
+        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved,
+        constraint = !market.outcome_tokens_enabled @ ErrorCode::AlreadyInitialized
+    )]
+    pub market: Account<'info, Market>,
+
+    /// YES outcome token mint (PDA, market is mint authority)
+    #[account(
+        init,
+        payer = founder,
+        seeds = [b"yes_mint", market.key().as_ref()],
+        bump,
+        mint::decimals = OUTCOME_TOKEN_DECIMALS,
+        mint::authority = market,
+        mint::token_program = token_program
+    )]
+    pub yes_mint: InterfaceAccount<'info, Mint>,
+
+    /// NO outcome token mint (PDA, market is mint authority)
+    #[account(
+        init,
+        payer = founder,
+        seeds = [b"no_mint", market.key().as_ref()],
+        bump,
+        mint::decimals = OUTCOME_TOKEN_DECIMALS,
+        mint::authority = market,
+        mint::token_program = token_program
+    )]
+    pub no_mint: InterfaceAccount<'info, Mint>,
+
+    /// Market founder (only they can configure the market)
+    #[account(mut)]
+    pub founder: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<EnableOutcomeTokens>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    // Configuration is frozen once trading starts
+    require!(
+        market.pool_balance == 0
+            && market.total_yes_shares == 0
+            && market.total_no_shares == 0,
+        ErrorCode::MarketAlreadyTrading
+    );
+
+    // Outcome tokens are burned whole on claim, so claims cannot be streamed
+    require!(
+        market.voter_vesting_duration == 0,
+        ErrorCode::VoterVestingUnsupported
+    );
+
+    market.outcome_tokens_enabled = true;
+    market.yes_net_invested = 0;
+    market.no_net_invested = 0;
+
+    msg!("🪙 Outcome tokens enabled");
+    msg!("   YES mint: {}", ctx.accounts.yes_mint.key());
+    msg!("   NO mint: {}", ctx.accounts.no_mint.key());
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Claw back unvested founder SOL and team tokens for YES voters (permissionless)
+///
+/// Conditions:
+/// - Market resolved YesWins and YES votes exceed half of total_yes_shares (vote_clawback)
+/// - Each vesting is clawed back once; vestings already revoked by the admin are skipped
+///
+/// Effects (same freeze as revoke_vesting):
+/// - Unlocks stop accruing now (market.*_vesting_revoked_at); amounts already
+///   unlocked stay claimable by the team/founder
+/// - Unvested team tokens stay in the market token account → market.clawback_tokens
+/// - Unvested founder SOL (incl. unattested milestones) stays in the market → market.clawback_sol
+/// - Founder never called init_founder_vesting: the whole founder_excess_sol_allocated
+///   → market.clawback_sol, and init_founder_vesting is closed for good
+/// - Claim deadline is pushed to now + claim period so voters can claim_clawback
+///
+/// Pass whichever vesting accounts exist; can be called again for one omitted earlier
+/// (until sweep_unclaimed has closed claims).
+#[derive(Accounts)]
+pub struct ExecuteClawback<'info> {
+    #[account(
+        mut,
+        constraint = market.resolution == MarketResolution::YesWins @ ErrorCode::InvalidResolutionState,
+        constraint = market.clawback_approved() @ ErrorCode::ClawbackNotApproved,
+        constraint = !market.unclaimed_swept @ ErrorCode::ClaimPeriodOver,
+        constraint = market.treasury == treasury.key() @ ErrorCode::Unauthorized
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Treasury (claim period)
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub caller: Signer<'info>,
+
+    /// Team vesting to claw back
+    #[account(
+        mut,
+        seeds = [b"team_vesting", market.key().as_ref()],
+        bump = team_vesting.bump
+    )]
+    pub team_vesting: Option<Account<'info, TeamVesting>>,
+
+    /// Founder SOL vesting to claw back
+    #[account(
+        mut,
+        seeds = [b"founder_vesting", market.key().as_ref()],
+        bump = founder_vesting.bump
+    )]
+    pub founder_vesting: Option<Account<'info, FounderVesting>>,
+
+    /// Founder milestones (required with founder_vesting when milestones are enabled)
+    #[account(
+        seeds = [b"founder_milestones", market.key().as_ref()],
+        bump = founder_milestones.bump
+    )]
+    pub founder_milestones: Option<Account<'info, FounderMilestones>>,
+}
+
+pub fn handler(ctx: Context<ExecuteClawback>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let now = Clock::get()?.unix_timestamp;
+    let mut clawed_back = false;
+
+    // -------------------------
+    // 1) Team token vesting
+    // -------------------------
+
+    if let Some(team_vesting) = ctx.accounts.team_vesting.as_mut() {
+        require!(market.team_vesting_revoked_at == 0, ErrorCode::AlreadyClaimed);
+
+        let unlocked_vested = team_vesting
+            .calculate_unlocked_vested_tokens(&market.team_vesting_schedule, now)?;
+        let unvested = team_vesting
+            .vesting_tokens
+            .checked_sub(unlocked_vested)
+            .ok_or(ErrorCode::MathError)?;
+
+        // Team can only ever receive immediate + vested-so-far
+        team_vesting.total_tokens = team_vesting
+            .total_tokens
+            .checked_sub(unvested)
+            .ok_or(ErrorCode::MathError)?;
+        market.clawback_tokens = market
+            .clawback_tokens
+            .checked_add(unvested)
+            .ok_or(ErrorCode::MathError)?;
+        market.team_vesting_revoked_at = now;
+        clawed_back = true;
+
+        msg!("   Team tokens clawed back: {}", unvested);
+    }
+
+    // -------------------------
+    // 2) Founder SOL vesting
+    // -------------------------
+
+    if let Some(founder_vesting) = ctx.accounts.founder_vesting.as_mut() {
+        require!(market.founder_vesting_revoked_at == 0, ErrorCode::AlreadyClaimed);
+
+        // Unattested milestone SOL counts as unvested
+        let milestones = if market.founder_milestones_enabled {
+            Some(
+                ctx.accounts
+                    .founder_milestones
+                    .as_deref()
+                    .ok_or(ErrorCode::InvalidMilestones)?,
+            )
+        } else {
+            None
+        };
+        let unlocked_vested = founder_vesting
+            .calculate_unlocked_vested_sol(&market.founder_vesting_schedule, milestones, now)?;
+        let unvested = founder_vesting
+            .vesting_sol
+            .checked_sub(unlocked_vested)
+            .ok_or(ErrorCode::MathError)?;
+
+        founder_vesting.total_sol = founder_vesting
+            .total_sol
+            .checked_sub(unvested)
+            .ok_or(ErrorCode::MathError)?;
+        market.clawback_sol = market
+            .clawback_sol
+            .checked_add(unvested)
+            .ok_or(ErrorCode::MathError)?;
+        market.founder_vesting_revoked_at = now;
+        clawed_back = true;
+
+        msg!("   Founder SOL clawed back: {} lamports", unvested);
+    } else if !market.founder_vesting_initialized
+        && market.founder_excess_sol_allocated > 0
+        && market.founder_vesting_revoked_at == 0
+    {
+        // Abandoned launch: nothing was ever vested, so all excess SOL goes back
+        let excess = market.founder_excess_sol_allocated;
+
+        market.clawback_sol = market
+            .clawback_sol
+            .checked_add(excess)
+            .ok_or(ErrorCode::MathError)?;
+        market.founder_vesting_revoked_at = now;
+        clawed_back = true;
+
+        msg!("   Uninitialized founder SOL clawed back: {} lamports", excess);
+    }
+
+    require!(clawed_back, ErrorCode::InvalidAccountData);
+
+    // -------------------------
+    // 3) Open the clawback claim window
+    // -------------------------
+
+    if market.clawback_executed_at == 0 {
+        market.clawback_executed_at = now;
+    }
+
+    let clawback_deadline = now
+        .checked_add(ctx.accounts.treasury.effective_claim_period())
+        .ok_or(ErrorCode::MathError)?;
+    market.claim_deadline = market.effective_claim_deadline().max(clawback_deadline);
+
+    msg!("↩️  Clawback executed");
+    msg!("   Market: {}", market.key());
+    msg!("   Claim deadline: {}", market.claim_deadline);
+
+    Ok(())
+}
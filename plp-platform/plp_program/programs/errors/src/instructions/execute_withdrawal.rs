@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Execute a queued treasury withdrawal after its timelock (admin only)
+///
+/// Checks at execution time:
+/// - executable_at has passed
+/// - Recipient is still on the allowlist
+/// - Per-epoch cap (Treasury.withdrawal_epoch_cap) is not exceeded
+/// - Treasury keeps its rent-exempt minimum plus the stakers' share of fees
+///   not yet moved by distribute_staking_rewards (once the staking pool exists)
+///
+/// The pending withdrawal account is closed (rent → admin).
+#[derive(Accounts)]
+pub struct ExecuteWithdrawal<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"withdrawal", withdrawal.id.to_le_bytes().as_ref()],
+        bump = withdrawal.bump,
+        close = admin
+    )]
+    pub withdrawal: Account<'info, PendingWithdrawal>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Must match withdrawal.recipient
+    #[account(
+        mut,
+        constraint = recipient.key() == withdrawal.recipient @ ErrorCode::Unauthorized
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Staking pool PDA, may not be initialized yet (then nothing is reserved)
+    #[account(
+        seeds = [b"staking_pool"],
+        bump
+    )]
+    pub staking_pool: UncheckedAccount<'info>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+}
+
+pub fn handler(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
+    authorize_admin_or_member(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+    )?;
+
+    let treasury = &mut ctx.accounts.treasury;
+    let withdrawal = &ctx.accounts.withdrawal;
+    let amount = withdrawal.amount;
+    let clock = Clock::get()?;
+
+    // -------------------------
+    // 1) Timelock and allowlist
+    // -------------------------
+
+    require!(
+        clock.unix_timestamp >= withdrawal.executable_at,
+        ErrorCode::WithdrawalTimelocked
+    );
+    require!(
+        treasury.is_allowed_recipient(&withdrawal.recipient),
+        ErrorCode::RecipientNotAllowed
+    );
+
+    // -------------------------
+    // 2) Per-epoch cap
+    // -------------------------
+
+    if treasury.withdrawal_epoch != clock.epoch {
+        treasury.withdrawal_epoch = clock.epoch;
+        treasury.epoch_withdrawn = 0;
+    }
+
+    let epoch_withdrawn = treasury
+        .epoch_withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathError)?;
+    require!(
+        treasury.withdrawal_epoch_cap == 0 || epoch_withdrawn <= treasury.withdrawal_epoch_cap,
+        ErrorCode::WithdrawalCapExceeded
+    );
+
+    // -------------------------
+    // 3) Stakers' undistributed share stays in the treasury
+    // -------------------------
+
+    let staking_pool_info = ctx.accounts.staking_pool.to_account_info();
+    let staker_reserve = if staking_pool_info.owner == ctx.program_id {
+        // try_deserialize checks the StakingPool discriminator
+        StakingPool::try_deserialize(&mut &staking_pool_info.try_borrow_data()?[..])?
+            .undistributed_reward(treasury.total_fees)
+    } else {
+        0
+    };
+
+    // -------------------------
+    // 4) Transfer lamports (treasury stays rent-exempt)
+    // -------------------------
+
+    let treasury_info = treasury.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let available = treasury_info
+        .lamports()
+        .saturating_sub(rent_exempt)
+        .saturating_sub(staker_reserve);
+    require!(amount <= available, ErrorCode::InsufficientBalance);
+
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.recipient.try_borrow_mut_lamports()? += amount;
+
+    treasury.epoch_withdrawn = epoch_withdrawn;
+    treasury.total_withdrawn = treasury
+        .total_withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathError)?;
+
+    msg!("💸 Withdrawal #{} executed", withdrawal.id);
+    msg!("   Amount: {} lamports", amount);
+    msg!("   Recipient: {}", withdrawal.recipient);
+    msg!("   Withdrawn this epoch: {} lamports", epoch_withdrawn);
+
+    Ok(())
+}
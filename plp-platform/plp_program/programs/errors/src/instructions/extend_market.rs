@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::PAUSE_EXTEND_MARKET;
 use crate::errors::ErrorCode;
 use crate::state::*;
 
@@ -20,13 +21,22 @@ pub struct ExtendMarket<'info> {
         mut,
         constraint = market.founder == founder.key() @ ErrorCode::Unauthorized,
         constraint = market.phase == MarketPhase::Prediction @ ErrorCode::InvalidMarketPhase,
-        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved
+        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved,
+        constraint = !market.is_paused(PAUSE_EXTEND_MARKET) @ ErrorCode::InstructionPaused
     )]
     pub market: Account<'info, Market>,
 
     /// Market founder (only they can extend)
     #[account(mut)]
     pub founder: Signer<'info>,
+
+    /// Global Treasury PDA (read for the emergency pause flags)
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        constraint = !treasury.is_paused(PAUSE_EXTEND_MARKET) @ ErrorCode::InstructionPaused
+    )]
+    pub treasury: Account<'info, Treasury>,
 }
 
 pub fn handler(ctx: Context<ExtendMarket>) -> Result<()> {
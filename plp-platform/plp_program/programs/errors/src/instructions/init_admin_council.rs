@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Create the admin council and switch the treasury to council mode (admin only)
+///
+/// Treasury.admin becomes the AdminCouncil PDA, so from now on admin-gated
+/// instructions only run with an approved AdminProposal (propose_admin_action /
+/// approve_admin_action). Run migrate_treasury first; to leave council mode,
+/// execute a SetAdmin proposal pointing at a wallet.
+#[derive(Accounts)]
+pub struct InitAdminCouncil<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        constraint = treasury.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminCouncil::SPACE,
+        seeds = [b"admin_council"],
+        bump
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+
+    /// Current admin (pays for the council account)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitAdminCouncil>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    AdminCouncil::validate(&members, threshold)?;
+
+    let admin_council = &mut ctx.accounts.admin_council;
+    admin_council.members = members;
+    admin_council.threshold = threshold;
+    admin_council.next_proposal_id = 0;
+    admin_council.bump = ctx.bumps.admin_council;
+
+    let treasury = &mut ctx.accounts.treasury;
+    let old_admin = treasury.admin;
+    treasury.admin = admin_council.key();
+
+    msg!("🏛️  Admin council created");
+    msg!("   Members: {}", admin_council.members.len());
+    msg!("   Threshold: {}", threshold);
+    msg!("   Admin changed from {} to council {}", old_admin, treasury.admin);
+
+    Ok(())
+}
@@ -8,14 +8,17 @@ use crate::state::*;
 /// Must be called after resolve_market when:
 /// - market.resolution == YesWins
 /// - market.founder_excess_sol_allocated > 0 (pool was > 50 SOL)
-/// Sets up 12-month linear vesting for founder's excess SOL (8% immediate + 92% vested)
+/// - The founder SOL has not been revoked or clawed back
+/// Sets up vesting for founder's excess SOL (8% immediate + 92% vested per market.founder_vesting_schedule)
 #[derive(Accounts)]
 pub struct InitFounderVesting<'info> {
     #[account(
         mut,
         constraint = market.resolution == MarketResolution::YesWins @ ErrorCode::InvalidResolutionState,
         constraint = market.founder_excess_sol_allocated > 0 @ ErrorCode::NoExcessSol,
-        constraint = !market.founder_vesting_initialized @ ErrorCode::AlreadyInitialized
+        constraint = !market.founder_vesting_initialized @ ErrorCode::AlreadyInitialized,
+        constraint = market.founder_vesting_revoked_at == 0 @ ErrorCode::VestingRevoked,
+        constraint = market.clawback_executed_at == 0 @ ErrorCode::VestingRevoked
     )]
     pub market: Account<'info, Market>,
 
@@ -66,7 +69,7 @@ pub fn handler(ctx: Context<InitFounderVesting>) -> Result<()> {
     founder_vesting.claimed_sol = 0;
     founder_vesting.immediate_claimed = false;
     founder_vesting.vesting_start = current_time;
-    founder_vesting.vesting_duration = FounderVesting::VESTING_DURATION_SECONDS;
+    founder_vesting.vesting_duration = market.founder_vesting_schedule.effective().duration_seconds;
     founder_vesting.bump = ctx.bumps.founder_vesting;
 
     // Mark as initialized in market state
@@ -75,7 +78,7 @@ pub fn handler(ctx: Context<InitFounderVesting>) -> Result<()> {
     msg!("✅ Founder SOL vesting initialized");
     msg!("   Total excess: {} lamports", total_excess);
     msg!("   Immediate (8%): {} lamports", immediate_sol);
-    msg!("   Vesting (92%): {} lamports over 12 months", vesting_sol);
+    msg!("   Vesting (92%): {} lamports over {} seconds", vesting_sol, founder_vesting.vesting_duration);
 
     Ok(())
 }
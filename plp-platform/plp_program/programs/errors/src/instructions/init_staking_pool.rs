@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::BPS_DIVISOR;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Create the platform token staking pool (admin only, one-time)
+///
+/// PDA layout:
+/// - Staking Pool PDA = seeds: ["staking_pool"]
+/// - Stake Vault PDA = seeds: ["stake_vault"] (token account, authority = staking pool)
+///
+/// Only fees collected after this call are shared with stakers
+/// (fees_checkpoint starts at the current Treasury.total_fees).
+#[derive(Accounts)]
+pub struct InitStakingPool<'info> {
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = StakingPool::SPACE,
+        seeds = [b"staking_pool"],
+        bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// Platform token that can be staked
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault holding staked tokens (PDA, staking pool is the authority)
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"stake_vault"],
+        bump,
+        token::mint = stake_mint,
+        token::authority = staking_pool,
+        token::token_program = token_program
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin (pays for the pool and vault accounts)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<InitStakingPool>, staker_share_bps: u16) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::InitStakingPool {
+            stake_mint: ctx.accounts.stake_mint.key(),
+            staker_share_bps,
+        },
+    )?;
+
+    require!(
+        staker_share_bps as u64 <= BPS_DIVISOR,
+        ErrorCode::InvalidStakerShare
+    );
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    staking_pool.stake_mint = ctx.accounts.stake_mint.key();
+    staking_pool.stake_vault = ctx.accounts.stake_vault.key();
+    staking_pool.staker_share_bps = staker_share_bps;
+    staking_pool.total_staked = 0;
+    staking_pool.reward_per_share = 0;
+    staking_pool.fees_checkpoint = ctx.accounts.treasury.total_fees;
+    staking_pool.total_rewards = 0;
+    staking_pool.total_claimed = 0;
+    staking_pool.bump = ctx.bumps.staking_pool;
+    staking_pool.vault_bump = ctx.bumps.stake_vault;
+
+    msg!("🥩 Staking pool created");
+    msg!("   Stake mint: {}", staking_pool.stake_mint);
+    msg!("   Staker share: {} bps", staker_share_bps);
+    msg!("   Fees checkpoint: {} lamports", staking_pool.fees_checkpoint);
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::{DEFAULT_CLAIM_PERIOD, DEFAULT_WITHDRAWAL_DELAY};
 use crate::state::Treasury;
 
 /// Initialize the treasury PDA (one-time operation)
@@ -44,6 +45,21 @@ pub fn handler(ctx: Context<InitTreasury>) -> Result<()> {
 
     t.admin = ctx.accounts.payer.key();
     t.total_fees = 0;
+    t.claim_period = DEFAULT_CLAIM_PERIOD;
+    t.paused = 0;
+    t.withdrawal_delay = DEFAULT_WITHDRAWAL_DELAY;
+    t.withdrawal_epoch_cap = 0;
+    t.withdrawal_epoch = 0;
+    t.epoch_withdrawn = 0;
+    t.total_withdrawn = 0;
+    t.next_withdrawal_id = 0;
+    t.set_withdrawal_allowlist(&[]);
+    t.creation_fees = 0;
+    t.trade_fees = 0;
+    t.completion_fees = 0;
+    t.platform_recipient = Pubkey::default();
+    t.founder_verifier = Pubkey::default();
+    t.verification_min_target = 0;
 
     let (_pda, bump) = Pubkey::find_program_address(&[b"treasury"], ctx.program_id);
     t.bump = bump;
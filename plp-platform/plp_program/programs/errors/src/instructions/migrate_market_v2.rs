@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{Market, MarketPhase, MarketResolution};
+use crate::state::{Market, MarketPhase, MarketResolution, VestingSchedule};
 
 /// Old Market struct before vesting fields were added
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -117,6 +117,38 @@ pub fn handler(ctx: Context<MigrateMarketV2>) -> Result<()> {
         founder_vesting_initialized: false,        // NEW FIELD
         treasury: old_market.treasury,
         bump: old_market.bump,
+        soft_close_window: 0,                      // v3: soft close disabled
+        soft_close_extension: 0,
+        hard_expiry_time: 0,
+        outcome_tokens_enabled: false,             // v3: legacy position-only shares
+        yes_net_invested: 0,
+        no_net_invested: 0,
+        claim_deadline: 0,                         // v3: legacy claim window
+        yes_voter_tokens_claimed: 0,
+        unclaimed_swept: false,
+        swept_sol: 0,
+        swept_tokens: 0,
+        token_account_closed: false,
+        team_tokens_allocated: 0,
+        team_vesting_schedule: VestingSchedule::default(),
+        founder_vesting_schedule: VestingSchedule::default(),
+        team_split_enabled: false,
+        vesting_revocable: false,
+        team_vesting_revoked_at: 0,
+        founder_vesting_revoked_at: 0,
+        voter_vesting_duration: 0,
+        resolved_at: 0,
+        founder_milestones_enabled: false,
+        clawback_votes: 0,
+        clawback_executed_at: 0,
+        clawback_tokens: 0,
+        clawback_sol: 0,
+        clawback_tokens_claimed: 0,
+        clawback_sol_claimed: 0,
+        paused: 0,
+        creation_fee_paid: 0,
+        trade_fees_generated: 0,
+        completion_fee_paid: 0,
     };
 
     // Serialize new market and write to account
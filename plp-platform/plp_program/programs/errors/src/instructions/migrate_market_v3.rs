@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::errors::ErrorCode;
+use crate::state::Market;
+
+/// Grow v2 Market accounts (480 bytes) to the current Market::SPACE
+///
+/// Fields added after v2 are appended at the end of Market, so existing
+/// data stays in place and the new space is zero-filled (zero = disabled).
+#[derive(Accounts)]
+pub struct MigrateMarketV3<'info> {
+    /// CHECK: Using UncheckedAccount because old account size may prevent deserialization
+    #[account(
+        mut,
+        owner = crate::ID @ ErrorCode::InvalidAccountData
+    )]
+    pub market: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateMarketV3>) -> Result<()> {
+    let account_info = ctx.accounts.market.to_account_info();
+    let current_space = account_info.data_len();
+    let new_space = Market::SPACE;
+
+    // Only Market accounts can be migrated
+    {
+        let data = account_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == Market::DISCRIMINATOR,
+            ErrorCode::InvalidAccountData
+        );
+    }
+
+    if current_space >= new_space {
+        msg!("Account already {} bytes, no reallocation needed", current_space);
+        return Ok(());
+    }
+
+    // Top up rent for the larger account
+    let rent = Rent::get()?;
+    let new_minimum_balance = rent.minimum_balance(new_space);
+    let current_lamports = account_info.lamports();
+
+    if new_minimum_balance > current_lamports {
+        let additional_rent = new_minimum_balance - current_lamports;
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            additional_rent,
+        )?;
+    }
+
+    // Zero-fill the new space so appended fields decode as defaults
+    account_info.realloc(new_space, true)?;
+
+    msg!("✅ Market reallocated from {} to {} bytes", current_space, new_space);
+    Ok(())
+}
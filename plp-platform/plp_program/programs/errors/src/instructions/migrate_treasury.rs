@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::errors::ErrorCode;
+use crate::state::{authorize_admin_key_or_member, AdminCouncil, Treasury};
+
+/// Grow the Treasury PDA to the current layout (admin, or any council member)
+///
+/// Fields added to Treasury are appended after `bump`, so existing data stays
+/// in place and the new space is zero-filled (zero = use the default).
+/// Must be run once after upgrading the program, before other treasury instructions.
+///
+/// In council mode the admin is the council PDA, which cannot sign; since the
+/// migration only grows the account (signer pays the rent), any member may run it.
+#[derive(Accounts)]
+pub struct MigrateTreasury<'info> {
+    /// CHECK: Using UncheckedAccount because old account size prevents deserialization
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+        owner = crate::ID @ ErrorCode::InvalidAccountData
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Current treasury admin or council member (pays additional rent)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Admin council (council mode only)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+}
+
+pub fn handler(ctx: Context<MigrateTreasury>) -> Result<()> {
+    let account_info = ctx.accounts.treasury.to_account_info();
+    let current_space = account_info.data_len();
+    let new_space = 8 + Treasury::INIT_SPACE;
+
+    // Validate discriminator and admin (admin is the first field after the discriminator)
+    {
+        let data = account_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 + 32 && data[..8] == Treasury::DISCRIMINATOR,
+            ErrorCode::InvalidAccountData
+        );
+        let admin = Pubkey::try_from(&data[8..40]).map_err(|_| ErrorCode::InvalidAccountData)?;
+        authorize_admin_key_or_member(
+            &admin,
+            &ctx.accounts.admin.key(),
+            ctx.accounts.admin_council.as_ref(),
+        )?;
+    }
+
+    if current_space >= new_space {
+        msg!("Treasury already {} bytes, no reallocation needed", current_space);
+        return Ok(());
+    }
+
+    // Top up rent for the larger account
+    let rent = Rent::get()?;
+    let new_minimum_balance = rent.minimum_balance(new_space);
+    let current_lamports = account_info.lamports();
+
+    if new_minimum_balance > current_lamports {
+        let additional_rent = new_minimum_balance - current_lamports;
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            additional_rent,
+        )?;
+    }
+
+    // Zero-fill the new space so appended fields decode as defaults
+    account_info.realloc(new_space, true)?;
+
+    msg!("✅ Treasury reallocated from {} to {} bytes", current_space, new_space);
+    Ok(())
+}
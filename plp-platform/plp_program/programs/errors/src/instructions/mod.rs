@@ -3,22 +3,63 @@
 // Treasury management
 pub mod init_treasury;
 pub mod set_admin;
-pub mod withdraw_fees;
+pub mod set_claim_period;
+pub mod set_pause;
+pub mod configure_withdrawals;
+pub mod queue_withdrawal;
+pub mod execute_withdrawal;
+pub mod cancel_withdrawal;
+pub mod set_platform_recipient;
+pub mod init_admin_council;
+pub mod propose_admin_action;
+pub mod approve_admin_action;
+pub mod update_admin_council;
 
 pub use init_treasury::*;
 pub use set_admin::*;
-pub use withdraw_fees::*;
+pub use set_claim_period::*;
+pub use set_pause::*;
+pub use configure_withdrawals::*;
+pub use queue_withdrawal::*;
+pub use execute_withdrawal::*;
+pub use cancel_withdrawal::*;
+pub use set_platform_recipient::*;
+pub use init_admin_council::*;
+pub use propose_admin_action::*;
+pub use approve_admin_action::*;
+pub use update_admin_council::*;
 
 // Market creation
 pub mod create_market;
+pub mod configure_soft_close;
+pub mod enable_outcome_tokens;
+pub mod configure_vesting;
+pub mod configure_team_split;
+pub mod set_founder_profile;
+pub mod verify_founder;
+pub mod configure_founder_verification;
+
 pub use create_market::*;
+pub use configure_soft_close::*;
+pub use enable_outcome_tokens::*;
+pub use configure_vesting::*;
+pub use configure_team_split::*;
+pub use set_founder_profile::*;
+pub use verify_founder::*;
+pub use configure_founder_verification::*;
 
 // Trading instructions
 pub mod buy_yes;
 pub mod buy_no;
+pub mod switch_side;
 
 pub use buy_yes::*;
 pub use buy_no::*;
+pub use switch_side::*;
+
+// Position management
+pub mod transfer_position;
+pub use transfer_position::*;
 
 // Market extension
 pub mod extend_market;
@@ -31,7 +72,18 @@ pub mod init_team_vesting;
 pub mod claim_team_tokens;
 pub mod init_founder_vesting;
 pub mod claim_founder_sol;
+pub mod rotate_vesting_beneficiary;
+pub mod revoke_vesting;
+pub mod configure_milestones;
+pub mod set_milestone_reviewer;
+pub mod attest_milestone;
+pub mod vote_clawback;
+pub mod execute_clawback;
+pub mod claim_clawback;
 pub mod claim_platform_tokens;
+pub mod claim_platform_tokens_batch;
+pub mod release_founder_bond;
+pub mod slash_founder_bond;
 
 pub use resolve_market::*;
 pub use claim_rewards::*;
@@ -39,20 +91,57 @@ pub use init_team_vesting::*;
 pub use claim_team_tokens::*;
 pub use init_founder_vesting::*;
 pub use claim_founder_sol::*;
+pub use rotate_vesting_beneficiary::*;
+pub use revoke_vesting::*;
+pub use configure_milestones::*;
+pub use set_milestone_reviewer::*;
+pub use attest_milestone::*;
+pub use vote_clawback::*;
+pub use execute_clawback::*;
+pub use claim_clawback::*;
 pub use claim_platform_tokens::*;
+pub use claim_platform_tokens_batch::*;
+pub use release_founder_bond::*;
+pub use slash_founder_bond::*;
 
 // Account cleanup (rent recovery)
 pub mod close_position;
 pub mod close_market;
-pub mod emergency_drain_vault;
+pub mod crank_claims;
+pub mod sweep_unclaimed;
+pub mod close_market_token_account;
+pub mod emergency_refund;
 
 pub use close_position::*;
 pub use close_market::*;
-pub use emergency_drain_vault::*;
+pub use crank_claims::*;
+pub use sweep_unclaimed::*;
+pub use close_market_token_account::*;
+pub use emergency_refund::*;
 
 // Migration
 pub mod migrate_market_v2;
+pub mod migrate_market_v3;
+pub mod migrate_treasury;
+
 pub use migrate_market_v2::*;
+pub use migrate_market_v3::*;
+pub use migrate_treasury::*;
+
+// Platform token staking
+pub mod init_staking_pool;
+pub mod set_staker_share;
+pub mod stake;
+pub mod unstake;
+pub mod distribute_staking_rewards;
+pub mod claim_staking_rewards;
+
+pub use init_staking_pool::*;
+pub use set_staker_share::*;
+pub use stake::*;
+pub use unstake::*;
+pub use distribute_staking_rewards::*;
+pub use claim_staking_rewards::*;
 
 // Legacy instructions (deprecated - commented out for now)
 // TODO: Fix compatibility issues in legacy instructions if needed
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::constants::ADMIN_PROPOSAL_TTL;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Propose an admin operation (council member only)
+///
+/// The proposer's approval is recorded immediately. Once `threshold` members
+/// have approved (approve_admin_action), any member can call the admin-gated
+/// instruction with the same arguments and pass this proposal. Expires after 7 days.
+#[derive(Accounts)]
+pub struct ProposeAdminAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"admin_council"],
+        bump = admin_council.bump,
+        constraint = admin_council.is_member(&proposer.key()) @ ErrorCode::NotCouncilMember
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = AdminProposal::SPACE,
+        seeds = [b"admin_proposal", admin_council.next_proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+
+    /// Council member proposing (pays for the proposal account)
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProposeAdminAction>, action: AdminAction) -> Result<()> {
+    let admin_council = &mut ctx.accounts.admin_council;
+    let admin_proposal = &mut ctx.accounts.admin_proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Council updates are validated up front so members don't approve a dead proposal
+    if let AdminAction::UpdateCouncil { members, threshold } = &action {
+        AdminCouncil::validate(members, *threshold)?;
+    }
+
+    admin_proposal.id = admin_council.next_proposal_id;
+    admin_proposal.proposer = ctx.accounts.proposer.key();
+    admin_proposal.action = action;
+    admin_proposal.approvals = vec![ctx.accounts.proposer.key()];
+    admin_proposal.created_at = now;
+    admin_proposal.expires_at = now
+        .checked_add(ADMIN_PROPOSAL_TTL)
+        .ok_or(ErrorCode::MathError)?;
+    admin_proposal.executed = false;
+    admin_proposal.bump = ctx.bumps.admin_proposal;
+
+    admin_council.next_proposal_id = admin_council
+        .next_proposal_id
+        .checked_add(1)
+        .ok_or(ErrorCode::MathError)?;
+
+    msg!("🏛️  Admin proposal #{} created", admin_proposal.id);
+    msg!("   Proposer: {}", admin_proposal.proposer);
+    msg!("   Action: {:?}", admin_proposal.action);
+    msg!("   Approvals: 1 / {}", admin_council.threshold);
+
+    Ok(())
+}
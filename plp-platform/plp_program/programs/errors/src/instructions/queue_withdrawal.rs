@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Queue a treasury withdrawal to an allowlisted recipient (admin only)
+///
+/// The withdrawal can be executed once Treasury.withdrawal_delay has passed
+/// (execute_withdrawal), or cancelled at any time (cancel_withdrawal).
+#[derive(Accounts)]
+pub struct QueueWithdrawal<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = PendingWithdrawal::SPACE,
+        seeds = [b"withdrawal", treasury.next_withdrawal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub withdrawal: Account<'info, PendingWithdrawal>,
+
+    /// Admin (pays for the pending withdrawal account)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: SOL recipient wallet, must be on the treasury allowlist
+    #[account(
+        constraint = treasury.is_allowed_recipient(&recipient.key()) @ ErrorCode::RecipientNotAllowed
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<QueueWithdrawal>, amount: u64) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::QueueWithdrawal {
+            recipient: ctx.accounts.recipient.key(),
+            amount,
+        },
+    )?;
+
+    require!(amount > 0, ErrorCode::InsufficientBalance);
+
+    let treasury = &mut ctx.accounts.treasury;
+    let withdrawal = &mut ctx.accounts.withdrawal;
+    let now = Clock::get()?.unix_timestamp;
+
+    withdrawal.id = treasury.next_withdrawal_id;
+    withdrawal.recipient = ctx.accounts.recipient.key();
+    withdrawal.amount = amount;
+    withdrawal.queued_at = now;
+    withdrawal.executable_at = now
+        .checked_add(treasury.effective_withdrawal_delay())
+        .ok_or(ErrorCode::MathError)?;
+    withdrawal.bump = ctx.bumps.withdrawal;
+
+    treasury.next_withdrawal_id = treasury
+        .next_withdrawal_id
+        .checked_add(1)
+        .ok_or(ErrorCode::MathError)?;
+
+    msg!("⏳ Withdrawal #{} queued", withdrawal.id);
+    msg!("   Amount: {} lamports", amount);
+    msg!("   Recipient: {}", withdrawal.recipient);
+    msg!("   Executable at: {}", withdrawal.executable_at);
+
+    Ok(())
+}
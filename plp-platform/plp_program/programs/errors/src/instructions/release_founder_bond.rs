@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::constants::FOUNDER_BOND_LOCK_PERIOD;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Return the founder's creation bond (permissionless, bond + rent → founder)
+///
+/// Conditions:
+/// - Market resolved and FOUNDER_BOND_LOCK_PERIOD passed since resolution
+///   (the admin's window to slash_founder_bond)
+/// - YesWins: token launched, team vesting initialized (pass team_vesting) and,
+///   if the pool had excess SOL, founder vesting initialized
+#[derive(Accounts)]
+pub struct ReleaseFounderBond<'info> {
+    #[account(
+        constraint = market.resolution != MarketResolution::Unresolved @ ErrorCode::BondLocked
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"founder_bond", market.key().as_ref()],
+        bump = founder_bond.bump,
+        close = founder
+    )]
+    pub founder_bond: Account<'info, FounderBond>,
+
+    /// CHECK: Must match founder_bond.founder (receives bond and rent)
+    #[account(
+        mut,
+        address = founder_bond.founder @ ErrorCode::Unauthorized
+    )]
+    pub founder: UncheckedAccount<'info>,
+
+    /// Team vesting (required for YesWins markets)
+    #[account(
+        seeds = [b"team_vesting", market.key().as_ref()],
+        bump = team_vesting.bump
+    )]
+    pub team_vesting: Option<Account<'info, TeamVesting>>,
+
+    pub caller: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ReleaseFounderBond>) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let now = Clock::get()?.unix_timestamp;
+
+    // -------------------------
+    // 1) Slashing window is over
+    // -------------------------
+
+    let unlock_at = market
+        .resolved_at
+        .checked_add(FOUNDER_BOND_LOCK_PERIOD)
+        .ok_or(ErrorCode::MathError)?;
+    require!(
+        market.resolved_at > 0 && now >= unlock_at,
+        ErrorCode::BondLocked
+    );
+
+    // -------------------------
+    // 2) Launch steps complete (YesWins)
+    // -------------------------
+
+    if market.resolution == MarketResolution::YesWins {
+        require!(
+            market.token_mint.is_some() && ctx.accounts.team_vesting.is_some(),
+            ErrorCode::BondLocked
+        );
+        require!(
+            market.founder_excess_sol_allocated == 0 || market.founder_vesting_initialized,
+            ErrorCode::BondLocked
+        );
+    }
+
+    msg!("🔓 Founder bond released");
+    msg!("   Market: {}", market.key());
+    msg!("   Amount: {} lamports → {}", ctx.accounts.founder_bond.amount, ctx.accounts.founder.key());
+
+    Ok(())
+}
@@ -15,14 +15,17 @@ use crate::state::*;
 ///    - If total_no_shares > total_yes_shares → NoWins (deduct 5% fee, prepare for distribution)
 ///    - If total_yes_shares == total_no_shares OR pool < target → Refund (no fees, full refund)
 /// 4. Deduct completion fee (5%) from pool if YES/NO wins
-/// 5. Update market.resolution status
+/// 5. Update market.resolution status and stamp the claim deadline
+/// 6. YesWins: record the team allocation and, if team_vesting is passed, start vesting
+/// 7. Count the outcome in the founder's FounderReputation (created here if needed)
 ///
 /// Anyone can call this after market expiry (permissionless resolution)
 #[derive(Accounts)]
 pub struct ResolveMarket<'info> {
     #[account(
         mut,
-        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved
+        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved,
+        constraint = !market.is_paused(PAUSE_RESOLVE_MARKET) @ ErrorCode::InstructionPaused
     )]
     pub market: Account<'info, Market>,
 
@@ -37,7 +40,8 @@ pub struct ResolveMarket<'info> {
     #[account(
         mut,
         seeds = [b"treasury"],
-        bump = treasury.bump
+        bump = treasury.bump,
+        constraint = !treasury.is_paused(PAUSE_RESOLVE_MARKET) @ ErrorCode::InstructionPaused
     )]
     pub treasury: Account<'info, Treasury>,
 
@@ -123,6 +127,27 @@ pub struct ResolveMarket<'info> {
     pub token_2022_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
+
+    /// Founder's launch track record (caller pays rent on first resolution)
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = FounderReputation::SPACE,
+        seeds = [b"founder_reputation", market.founder.as_ref()],
+        bump
+    )]
+    pub founder_reputation: Account<'info, FounderReputation>,
+
+    /// Team vesting PDA, created here when YES wins (optional, caller pays rent)
+    /// Omit to initialize later via init_team_vesting
+    #[account(
+        init,
+        payer = caller,
+        space = TeamVesting::SPACE,
+        seeds = [b"team_vesting", market.key().as_ref()],
+        bump
+    )]
+    pub team_vesting: Option<Account<'info, TeamVesting>>,
 }
 
 pub fn handler(ctx: Context<ResolveMarket>) -> Result<()> {
@@ -436,11 +461,9 @@ pub fn handler(ctx: Context<ResolveMarket>) -> Result<()> {
                 completion_fee,
             )?;
 
-            // Update treasury total fees
-            treasury.total_fees = treasury
-                .total_fees
-                .checked_add(completion_fee)
-                .ok_or(ErrorCode::MathError)?;
+            // Update treasury fee ledger and market fee counter
+            treasury.record_fee(FeeSource::Completion, completion_fee)?;
+            market.completion_fee_paid = completion_fee;
 
             // -------------------------
             // Handle excess SOL if any (transfer to market account for founder vesting)
@@ -506,6 +529,19 @@ pub fn handler(ctx: Context<ResolveMarket>) -> Result<()> {
             // Store token allocations
             market.platform_tokens_allocated = platform_tokens;
             market.yes_voter_tokens_allocated = yes_voter_tokens;
+            market.team_tokens_allocated = team_tokens;
+
+            // Start team vesting now if the caller passed the vesting account
+            if let Some(team_vesting) = ctx.accounts.team_vesting.as_mut() {
+                let market_key = market.key();
+                team_vesting.initialize(
+                    market,
+                    market_key,
+                    team_tokens,
+                    now,
+                    ctx.bumps.team_vesting.ok_or(ErrorCode::MathError)?,
+                )?;
+            }
 
         }
 
@@ -538,11 +574,9 @@ pub fn handler(ctx: Context<ResolveMarket>) -> Result<()> {
                 completion_fee,
             )?;
 
-            // Update treasury total fees
-            treasury.total_fees = treasury
-                .total_fees
-                .checked_add(completion_fee)
-                .ok_or(ErrorCode::MathError)?;
+            // Update treasury fee ledger and market fee counter
+            treasury.record_fee(FeeSource::Completion, completion_fee)?;
+            market.completion_fee_paid = completion_fee;
 
             // Calculate remaining vault balance for distribution
             let distribution_amount = vault_lamports
@@ -622,7 +656,40 @@ pub fn handler(ctx: Context<ResolveMarket>) -> Result<()> {
     // 4) Update market resolution state
     // -------------------------
 
+    // Team vesting only exists for YesWins markets
+    require!(
+        resolution == MarketResolution::YesWins || ctx.accounts.team_vesting.is_none(),
+        ErrorCode::InvalidResolutionState
+    );
+
     market.resolution = resolution;
+    market.resolved_at = now;
+
+    // Claim window starts at resolution (unclaimed funds can be swept afterwards)
+    // With voter vesting, it starts once voter tokens are fully unlocked
+    market.claim_deadline = now
+        .checked_add(market.voter_vesting_duration)
+        .and_then(|t| t.checked_add(treasury.effective_claim_period()))
+        .ok_or(ErrorCode::MathError)?;
+
+    // -------------------------
+    // 5) Update founder reputation
+    // -------------------------
+
+    let founder_reputation = &mut ctx.accounts.founder_reputation;
+    if founder_reputation.founder == Pubkey::default() {
+        founder_reputation.founder = market.founder;
+        founder_reputation.bump = ctx.bumps.founder_reputation;
+    }
+    founder_reputation.record_resolution(resolution)?;
+
+    msg!(
+        "🏅 Founder reputation: {} launched ({} YES / {} NO / {} refund)",
+        founder_reputation.markets_launched,
+        founder_reputation.yes_wins,
+        founder_reputation.no_wins,
+        founder_reputation.refunds
+    );
 
     Ok(())
 }
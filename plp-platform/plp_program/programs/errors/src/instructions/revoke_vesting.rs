@@ -0,0 +1,268 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Revoke unvested team tokens and/or founder SOL (platform admin only)
+///
+/// Conditions:
+/// - Market opted in to governance via configure_vesting(revocable = true)
+/// - Market resolved YesWins and the vesting account exists
+/// - Each vesting can be revoked once; amounts already unlocked stay claimable
+///
+/// Effects:
+/// - Unlocks stop accruing at the revocation time (market.*_vesting_revoked_at)
+/// - Unvested team tokens → treasury token account, or added to the YES voter
+///   allocation (only while no voter has claimed, so every voter gets the same rate)
+/// - Unvested founder SOL (held by the market account) → treasury PDA, or left in
+///   the market as clawback SOL that YES holders claim via claim_clawback (the
+///   claim deadline is extended by a full claim period, as in slash_founder_bond)
+#[derive(Accounts)]
+pub struct RevokeVesting<'info> {
+    #[account(
+        mut,
+        constraint = market.vesting_revocable @ ErrorCode::VestingNotRevocable,
+        constraint = market.resolution == MarketResolution::YesWins @ ErrorCode::InvalidResolutionState,
+        constraint = market.treasury == treasury.key() @ ErrorCode::Unauthorized
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub admin: Signer<'info>,
+
+    /// Team vesting to revoke (omit to leave team vesting untouched)
+    #[account(
+        mut,
+        seeds = [b"team_vesting", market.key().as_ref()],
+        bump = team_vesting.bump
+    )]
+    pub team_vesting: Option<Account<'info, TeamVesting>>,
+
+    /// Founder SOL vesting to revoke (omit to leave founder vesting untouched)
+    #[account(
+        mut,
+        seeds = [b"founder_vesting", market.key().as_ref()],
+        bump = founder_vesting.bump
+    )]
+    pub founder_vesting: Option<Account<'info, FounderVesting>>,
+
+    // -------------------------
+    // Token accounts (team revocation to Treasury only)
+    // -------------------------
+
+    /// Market's token account (holds unvested team tokens)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = market,
+        token::token_program = token_program
+    )]
+    pub market_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury's token account (receives unvested team tokens)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = treasury,
+        token::token_program = token_program
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Founder milestones (required to revoke founder vesting when milestones are enabled)
+    #[account(
+        seeds = [b"founder_milestones", market.key().as_ref()],
+        bump = founder_milestones.bump
+    )]
+    pub founder_milestones: Option<Account<'info, FounderMilestones>>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<RevokeVesting>, destination: RevokeDestination) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::RevokeVesting {
+            market: ctx.accounts.market.key(),
+            destination,
+        },
+    )?;
+
+    let market = &mut ctx.accounts.market;
+    let now = Clock::get()?.unix_timestamp;
+    let mut revoked = false;
+
+    // -------------------------
+    // 1) Team token vesting
+    // -------------------------
+
+    if let Some(team_vesting) = ctx.accounts.team_vesting.as_mut() {
+        require!(market.team_vesting_revoked_at == 0, ErrorCode::AlreadyClaimed);
+
+        let unlocked_vested = team_vesting
+            .calculate_unlocked_vested_tokens(&market.team_vesting_schedule, now)?;
+        let unvested = team_vesting
+            .vesting_tokens
+            .checked_sub(unlocked_vested)
+            .ok_or(ErrorCode::MathError)?;
+
+        if unvested > 0 {
+            match destination {
+                RevokeDestination::Treasury => {
+                    let (Some(market_token_account), Some(treasury_token_account), Some(token_mint), Some(token_program)) = (
+                        ctx.accounts.market_token_account.as_ref(),
+                        ctx.accounts.treasury_token_account.as_ref(),
+                        ctx.accounts.token_mint.as_ref(),
+                        ctx.accounts.token_program.as_ref(),
+                    ) else {
+                        return Err(ErrorCode::InvalidAccountData.into());
+                    };
+
+                    require!(
+                        Some(token_mint.key()) == market.token_mint,
+                        ErrorCode::Unauthorized
+                    );
+
+                    // Create PDA signer seeds for market account
+                    let founder_key = market.founder;
+                    let ipfs_hash = anchor_lang::solana_program::hash::hash(market.ipfs_cid.as_bytes());
+                    let market_seeds = &[
+                        b"market",
+                        founder_key.as_ref(),
+                        ipfs_hash.as_ref(),
+                        &[market.bump],
+                    ];
+                    let signer_seeds = &[&market_seeds[..]];
+
+                    let transfer_ctx = CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: market_token_account.to_account_info(),
+                            to: treasury_token_account.to_account_info(),
+                            authority: market.to_account_info(),
+                            mint: token_mint.to_account_info(),
+                        },
+                        signer_seeds,
+                    );
+
+                    token_interface::transfer_checked(transfer_ctx, unvested, token_mint.decimals)?;
+                }
+                RevokeDestination::YesVoters => {
+                    // Raising the allocation after some voters claimed would pay
+                    // later claimers a better rate, so only allowed before any claim
+                    require!(
+                        market.yes_voter_tokens_claimed == 0 && !market.unclaimed_swept,
+                        ErrorCode::AlreadyClaimed
+                    );
+                    market.yes_voter_tokens_allocated = market
+                        .yes_voter_tokens_allocated
+                        .checked_add(unvested)
+                        .ok_or(ErrorCode::MathError)?;
+                }
+            }
+        }
+
+        // Team can only ever receive immediate + vested-so-far
+        team_vesting.total_tokens = team_vesting
+            .total_tokens
+            .checked_sub(unvested)
+            .ok_or(ErrorCode::MathError)?;
+        market.team_vesting_revoked_at = now;
+        revoked = true;
+
+        msg!("   Team tokens revoked: {} ({:?})", unvested, destination);
+    }
+
+    // -------------------------
+    // 2) Founder SOL vesting
+    // -------------------------
+
+    if let Some(founder_vesting) = ctx.accounts.founder_vesting.as_mut() {
+        require!(market.founder_vesting_revoked_at == 0, ErrorCode::AlreadyClaimed);
+
+        // Unattested milestone SOL counts as unvested
+        let milestones = if market.founder_milestones_enabled {
+            Some(
+                ctx.accounts
+                    .founder_milestones
+                    .as_deref()
+                    .ok_or(ErrorCode::InvalidMilestones)?,
+            )
+        } else {
+            None
+        };
+        let unlocked_vested = founder_vesting
+            .calculate_unlocked_vested_sol(&market.founder_vesting_schedule, milestones, now)?;
+        let unvested = founder_vesting
+            .vesting_sol
+            .checked_sub(unlocked_vested)
+            .ok_or(ErrorCode::MathError)?;
+
+        if unvested > 0 {
+            match destination {
+                RevokeDestination::Treasury => {
+                    // Never dip into the market account's own rent-exempt minimum
+                    let rent = Rent::get()?;
+                    let market_info = market.to_account_info();
+                    let rent_exempt = rent.minimum_balance(market_info.data_len());
+                    let available = market_info.lamports().saturating_sub(rent_exempt);
+                    require!(unvested <= available, ErrorCode::InsufficientBalance);
+
+                    **market_info.try_borrow_mut_lamports()? -= unvested;
+                    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += unvested;
+                }
+                RevokeDestination::YesVoters => {
+                    // SOL stays in the market account as clawback SOL
+                    require!(!market.unclaimed_swept, ErrorCode::ClaimPeriodOver);
+
+                    market.clawback_sol = market
+                        .clawback_sol
+                        .checked_add(unvested)
+                        .ok_or(ErrorCode::MathError)?;
+
+                    let clawback_deadline = now
+                        .checked_add(ctx.accounts.treasury.effective_claim_period())
+                        .ok_or(ErrorCode::MathError)?;
+                    market.claim_deadline = market.effective_claim_deadline().max(clawback_deadline);
+                }
+            }
+        }
+
+        founder_vesting.total_sol = founder_vesting
+            .total_sol
+            .checked_sub(unvested)
+            .ok_or(ErrorCode::MathError)?;
+        market.founder_vesting_revoked_at = now;
+        revoked = true;
+
+        msg!("   Founder SOL revoked: {} lamports ({:?})", unvested, destination);
+    }
+
+    require!(revoked, ErrorCode::InvalidAccountData);
+
+    msg!("⛔ Vesting revoked");
+    msg!("   Market: {}", market.key());
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Move future vesting unlocks to a new wallet (signed by the current beneficiary)
+///
+/// Rotates every passed vesting account where the signer is the beneficiary:
+/// - team_vesting: team_wallet (single-wallet team vesting)
+/// - team_split: the signer's beneficiary entry (claimed total carries over)
+/// - founder_vesting: founder SOL beneficiary
+///
+/// Lets a founder or team member move unlocks away from a compromised or
+/// retired wallet. Already-claimed amounts are unaffected.
+#[derive(Accounts)]
+pub struct RotateVestingBeneficiary<'info> {
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"team_vesting", market.key().as_ref()],
+        bump = team_vesting.bump
+    )]
+    pub team_vesting: Option<Account<'info, TeamVesting>>,
+
+    #[account(
+        mut,
+        seeds = [b"team_split", market.key().as_ref()],
+        bump = team_split.bump
+    )]
+    pub team_split: Option<Account<'info, TeamSplit>>,
+
+    #[account(
+        mut,
+        seeds = [b"founder_vesting", market.key().as_ref()],
+        bump = founder_vesting.bump
+    )]
+    pub founder_vesting: Option<Account<'info, FounderVesting>>,
+
+    /// Current beneficiary
+    pub beneficiary: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RotateVestingBeneficiary>, new_beneficiary: Pubkey) -> Result<()> {
+    let current = ctx.accounts.beneficiary.key();
+
+    require!(
+        new_beneficiary != current && new_beneficiary != Pubkey::default(),
+        ErrorCode::InvalidAccountData
+    );
+
+    let mut rotated = 0u8;
+
+    // -------------------------
+    // 1) Single-wallet team vesting
+    // -------------------------
+
+    if let Some(team_vesting) = ctx.accounts.team_vesting.as_mut() {
+        if team_vesting.team_wallet == current {
+            team_vesting.team_wallet = new_beneficiary;
+            rotated += 1;
+            msg!("   Team vesting: {} → {}", current, new_beneficiary);
+        }
+    }
+
+    // -------------------------
+    // 2) Team split entry
+    // -------------------------
+
+    if let Some(team_split) = ctx.accounts.team_split.as_mut() {
+        if let Some(index) = team_split.find(&current) {
+            // A wallet may only hold one share
+            require!(
+                team_split.find(&new_beneficiary).is_none(),
+                ErrorCode::InvalidTeamSplit
+            );
+            team_split.beneficiaries[index].wallet = new_beneficiary;
+            rotated += 1;
+            msg!("   Team split share: {} → {}", current, new_beneficiary);
+        }
+    }
+
+    // -------------------------
+    // 3) Founder SOL vesting
+    // -------------------------
+
+    if let Some(founder_vesting) = ctx.accounts.founder_vesting.as_mut() {
+        if founder_vesting.founder == current {
+            founder_vesting.founder = new_beneficiary;
+            rotated += 1;
+            msg!("   Founder vesting: {} → {}", current, new_beneficiary);
+        }
+    }
+
+    require!(rotated > 0, ErrorCode::Unauthorized);
+
+    msg!("🔁 Rotated {} vesting beneficiary slot(s)", rotated);
+    msg!("   Market: {}", ctx.accounts.market.key());
+
+    Ok(())
+}
@@ -1,6 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::errors::ErrorCode;
-use crate::state::Treasury;
+use crate::state::*;
 
 /// Allows the current admin to assign a new admin wallet.
 #[derive(Accounts)]
@@ -8,16 +7,34 @@ pub struct SetAdmin<'info> {
     #[account(
         mut,
         seeds = [b"treasury"],
-        bump = treasury.bump,
-        constraint = treasury.admin == current_admin.key() @ ErrorCode::Unauthorized
+        bump = treasury.bump
     )]
     pub treasury: Account<'info, Treasury>,
 
     #[account(mut)]
-    pub current_admin: Signer<'info>, // must be the current admin
+    pub current_admin: Signer<'info>, // current admin (or council member with an approved proposal)
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
 }
 
 pub fn handler(ctx: Context<SetAdmin>, new_admin: Pubkey) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.current_admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::SetAdmin { new_admin },
+    )?;
+
     let treasury = &mut ctx.accounts.treasury;
     let old_admin = treasury.admin;
     treasury.admin = new_admin;
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_CLAIM_PERIOD, MIN_CLAIM_PERIOD};
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Allows the admin to change the claim window applied to newly resolved markets.
+/// Markets keep the deadline stamped when they were resolved.
+#[derive(Accounts)]
+pub struct SetClaimPeriod<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub admin: Signer<'info>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<SetClaimPeriod>, claim_period: i64) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::SetClaimPeriod { claim_period },
+    )?;
+
+    require!(
+        (MIN_CLAIM_PERIOD..=MAX_CLAIM_PERIOD).contains(&claim_period),
+        ErrorCode::InvalidClaimPeriod
+    );
+
+    let treasury = &mut ctx.accounts.treasury;
+    let old_period = treasury.claim_period;
+    treasury.claim_period = claim_period;
+
+    msg!("⏳ Claim period changed from {} to {} seconds", old_period, claim_period);
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Create or update the caller's founder profile
+///
+/// Changing the handle or website hash clears verification, so a verified
+/// badge always refers to the details that were reviewed.
+#[derive(Accounts)]
+pub struct SetFounderProfile<'info> {
+    #[account(
+        init_if_needed,
+        payer = founder,
+        space = FounderProfile::SPACE,
+        seeds = [b"founder_profile", founder.key().as_ref()],
+        bump
+    )]
+    pub founder_profile: Account<'info, FounderProfile>,
+
+    #[account(mut)]
+    pub founder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetFounderProfile>,
+    handle_hash: [u8; 32],
+    website_hash: [u8; 32],
+) -> Result<()> {
+    let founder_profile = &mut ctx.accounts.founder_profile;
+
+    if founder_profile.founder == Pubkey::default() {
+        founder_profile.founder = ctx.accounts.founder.key();
+        founder_profile.bump = ctx.bumps.founder_profile;
+    }
+
+    let changed = founder_profile.handle_hash != handle_hash
+        || founder_profile.website_hash != website_hash;
+
+    if changed && founder_profile.verified {
+        founder_profile.verified = false;
+        founder_profile.verified_at = Clock::get()?.unix_timestamp;
+        msg!("   Verification cleared (profile details changed)");
+    }
+
+    founder_profile.handle_hash = handle_hash;
+    founder_profile.website_hash = website_hash;
+
+    msg!("🪪 Founder profile updated");
+    msg!("   Founder: {}", founder_profile.founder);
+    msg!("   Verified: {}", founder_profile.verified);
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Appoint the reviewer allowed to attest a market's founder milestones (admin only)
+///
+/// Pass Pubkey::default() to remove the reviewer; milestones can then only be
+/// attested by a YES holder majority.
+#[derive(Accounts)]
+pub struct SetMilestoneReviewer<'info> {
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"founder_milestones", market.key().as_ref()],
+        bump = founder_milestones.bump
+    )]
+    pub founder_milestones: Account<'info, FounderMilestones>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub admin: Signer<'info>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<SetMilestoneReviewer>, reviewer: Pubkey) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::SetMilestoneReviewer {
+            market: ctx.accounts.market.key(),
+            reviewer,
+        },
+    )?;
+
+    let founder_milestones = &mut ctx.accounts.founder_milestones;
+
+    // The founder cannot review their own milestones
+    require!(
+        reviewer != ctx.accounts.market.founder,
+        ErrorCode::Unauthorized
+    );
+
+    let old_reviewer = founder_milestones.reviewer;
+    founder_milestones.reviewer = reviewer;
+
+    msg!("🧑‍⚖️ Milestone reviewer changed from {} to {}", old_reviewer, reviewer);
+    Ok(())
+}
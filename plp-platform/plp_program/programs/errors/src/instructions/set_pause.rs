@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::constants::PAUSE_ALL;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Emergency pause of individual instructions (admin only)
+///
+/// `paused` is a PAUSE_* bitmask (see constants) that replaces the current one:
+/// - market omitted: applies platform-wide (Treasury.paused)
+/// - market passed: applies to that market only (Market.paused)
+///
+/// Pausable: create_market (global only), buy_yes, buy_no (and switch_side),
+/// extend_market, resolve_market. Claims, refunds and vesting always stay available.
+/// Pass 0 to unpause.
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub admin: Signer<'info>,
+
+    /// Market to pause (omit for a platform-wide pause)
+    #[account(
+        mut,
+        constraint = market.treasury == treasury.key() @ ErrorCode::Unauthorized
+    )]
+    pub market: Option<Account<'info, Market>>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<SetPause>, paused: u8) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::SetPause {
+            market: ctx.accounts.market.as_ref().map(|m| m.key()),
+            paused,
+        },
+    )?;
+
+    require!(paused & !PAUSE_ALL == 0, ErrorCode::InvalidPauseFlags);
+
+    if let Some(market) = ctx.accounts.market.as_mut() {
+        let old_paused = market.paused;
+        market.paused = paused;
+
+        msg!("⏸️  Market pause flags changed from {:#04x} to {:#04x}", old_paused, paused);
+        msg!("   Market: {}", market.key());
+    } else {
+        let treasury = &mut ctx.accounts.treasury;
+        let old_paused = treasury.paused;
+        treasury.paused = paused;
+
+        msg!("⏸️  Global pause flags changed from {:#04x} to {:#04x}", old_paused, paused);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Change the wallet that receives platform token allocations (admin only)
+///
+/// Pass Pubkey::default() to fall back to the P&L wallet (PNL_WALLET).
+/// Allocations not yet claimed go to the new recipient.
+#[derive(Accounts)]
+pub struct SetPlatformRecipient<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub admin: Signer<'info>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<SetPlatformRecipient>, recipient: Pubkey) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::SetPlatformRecipient { recipient },
+    )?;
+
+    let treasury = &mut ctx.accounts.treasury;
+    let old_recipient = treasury.effective_platform_recipient();
+    treasury.platform_recipient = recipient;
+
+    msg!(
+        "🏦 Platform token recipient changed from {} to {}",
+        old_recipient,
+        treasury.effective_platform_recipient()
+    );
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::constants::BPS_DIVISOR;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Change the share of treasury fee inflows paid to stakers (admin only)
+///
+/// Applies to fees not yet distributed: run distribute_staking_rewards first
+/// to pay out fees collected so far at the old share.
+#[derive(Accounts)]
+pub struct SetStakerShare<'info> {
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_pool"],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    pub admin: Signer<'info>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<SetStakerShare>, staker_share_bps: u16) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::SetStakerShare { staker_share_bps },
+    )?;
+
+    require!(
+        staker_share_bps as u64 <= BPS_DIVISOR,
+        ErrorCode::InvalidStakerShare
+    );
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let old_share = staking_pool.staker_share_bps;
+    staking_pool.staker_share_bps = staker_share_bps;
+
+    msg!("🥩 Staker share changed from {} to {} bps", old_share, staker_share_bps);
+    Ok(())
+}
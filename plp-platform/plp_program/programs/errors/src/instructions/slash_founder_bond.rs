@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Slash a founder's creation bond (admin only)
+///
+/// For markets flagged as a rug, or whose founder never completed the launch
+/// steps. Possible any time before release_founder_bond.
+/// - Treasury: bond → treasury PDA
+/// - YesVoters (YesWins only): bond → market account, added to the clawback SOL
+///   pool that YES holders claim pro-rata via claim_clawback; the claim
+///   deadline is extended by a full claim period. Only while claims are open
+///   (before sweep_unclaimed), which later sweeps the unclaimed part to the
+///   treasury; close_market is blocked until then, so it never reaches the founder
+///
+/// The bond account is closed (rent → treasury).
+#[derive(Accounts)]
+pub struct SlashFounderBond<'info> {
+    #[account(
+        mut,
+        constraint = market.treasury == treasury.key() @ ErrorCode::Unauthorized
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"founder_bond", market.key().as_ref()],
+        bump = founder_bond.bump,
+        close = treasury
+    )]
+    pub founder_bond: Account<'info, FounderBond>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub admin: Signer<'info>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<SlashFounderBond>, destination: BondSlashDestination) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::SlashFounderBond {
+            market: ctx.accounts.market.key(),
+            destination,
+        },
+    )?;
+
+    let amount = ctx.accounts.founder_bond.amount;
+
+    if destination == BondSlashDestination::YesVoters {
+        let market = &mut ctx.accounts.market;
+        require!(
+            market.resolution == MarketResolution::YesWins,
+            ErrorCode::InvalidResolutionState
+        );
+        require!(!market.unclaimed_swept, ErrorCode::ClaimPeriodOver);
+
+        // -------------------------
+        // Move bond into the market account's clawback SOL pool
+        // -------------------------
+
+        **ctx.accounts.founder_bond.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **market.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        market.clawback_sol = market
+            .clawback_sol
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathError)?;
+
+        let clawback_deadline = Clock::get()?
+            .unix_timestamp
+            .checked_add(ctx.accounts.treasury.effective_claim_period())
+            .ok_or(ErrorCode::MathError)?;
+        market.claim_deadline = market.effective_claim_deadline().max(clawback_deadline);
+    }
+    // Treasury: `close = treasury` moves the bond together with the rent
+
+    msg!("⚔️  Founder bond slashed");
+    msg!("   Market: {}", ctx.accounts.market.key());
+    msg!("   Founder: {}", ctx.accounts.founder_bond.founder);
+    msg!("   Amount: {} lamports ({:?})", amount, destination);
+
+    Ok(())
+}
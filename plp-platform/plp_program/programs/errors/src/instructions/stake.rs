@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::constants::STAKE_LOCK_PERIOD;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Stake platform tokens to earn a share of treasury fees
+///
+/// Rewards earned so far are settled into the position first, so new tokens
+/// only earn from distributions after this call. The whole position is then
+/// locked for STAKE_LOCK_PERIOD (see unstake).
+///
+/// The position is credited with what the stake vault actually received, so
+/// Token-2022 transfer fees never leave the vault short of total_staked.
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_pool"],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = StakePosition::SPACE,
+        seeds = [b"stake", owner.key().as_ref()],
+        bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    #[account(
+        mut,
+        address = staking_pool.stake_vault @ ErrorCode::Unauthorized
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Owner's platform token account
+    #[account(
+        mut,
+        token::mint = stake_mint,
+        token::authority = owner,
+        token::token_program = token_program
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = staking_pool.stake_mint @ ErrorCode::Unauthorized,
+        mint::token_program = token_program
+    )]
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Stake>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidStakeAmount);
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let stake_position = &mut ctx.accounts.stake_position;
+
+    // -------------------------
+    // 1) Settle rewards earned on the current amount
+    // -------------------------
+
+    if stake_position.owner == Pubkey::default() {
+        stake_position.owner = ctx.accounts.owner.key();
+        stake_position.bump = ctx.bumps.stake_position;
+    }
+    stake_position.settle(staking_pool.reward_per_share)?;
+
+    // -------------------------
+    // 2) Transfer tokens into the stake vault
+    // -------------------------
+
+    let vault_before = ctx.accounts.stake_vault.amount;
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.owner_token_account.to_account_info(),
+            to: ctx.accounts.stake_vault.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+            mint: ctx.accounts.stake_mint.to_account_info(),
+        },
+    );
+
+    token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.stake_mint.decimals)?;
+
+    ctx.accounts.stake_vault.reload()?;
+    let received = ctx
+        .accounts
+        .stake_vault
+        .amount
+        .checked_sub(vault_before)
+        .ok_or(ErrorCode::MathError)?;
+    require!(received > 0, ErrorCode::InvalidStakeAmount);
+
+    // -------------------------
+    // 3) Update balances
+    // -------------------------
+
+    stake_position.amount = stake_position
+        .amount
+        .checked_add(received)
+        .ok_or(ErrorCode::MathError)?;
+    stake_position.reset_debt(staking_pool.reward_per_share)?;
+    stake_position.locked_until = Clock::get()?
+        .unix_timestamp
+        .checked_add(STAKE_LOCK_PERIOD)
+        .ok_or(ErrorCode::MathError)?;
+
+    staking_pool.total_staked = staking_pool
+        .total_staked
+        .checked_add(received)
+        .ok_or(ErrorCode::MathError)?;
+
+    msg!("🥩 Staked {} tokens ({} received)", amount, received);
+    msg!("   Owner: {}", stake_position.owner);
+    msg!("   Position: {} / pool total {}", stake_position.amount, staking_pool.total_staked);
+    msg!("   Locked until: {}", stake_position.locked_until);
+
+    Ok(())
+}
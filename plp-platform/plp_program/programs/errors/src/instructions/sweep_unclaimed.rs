@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Sweep unclaimed funds to the treasury after the claim deadline (admin only)
+///
+/// Moves whatever users did not claim before `market.claim_deadline`:
+/// - NoWins / Refund: remaining pool SOL in the market account → treasury PDA
+/// - YesWins: unclaimed YES voter tokens (allocated - claimed) and unclaimed
+///   clawed back tokens → treasury token account; unclaimed clawed back SOL
+///   (incl. founder bonds slashed to YES voters) → treasury PDA
+///   (team vesting, founder vesting and platform allocations are left untouched)
+///
+/// Records swept amounts on the market and closes claims, after which
+/// close_market is no longer blocked on PoolNotEmpty.
+#[derive(Accounts)]
+pub struct SweepUnclaimed<'info> {
+    #[account(
+        mut,
+        constraint = market.resolution != MarketResolution::Unresolved @ ErrorCode::InvalidResolutionState,
+        constraint = !market.unclaimed_swept @ ErrorCode::AlreadyClaimed,
+        constraint = market.treasury == treasury.key() @ ErrorCode::Unauthorized
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // -------------------------
+    // Token accounts (YesWins only)
+    // -------------------------
+
+    /// Market's token account (holds unclaimed voter tokens)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = market,
+        token::token_program = token_program
+    )]
+    pub market_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury's token account (receives unclaimed voter tokens)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = treasury,
+        token::token_program = token_program
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Admin council (council mode only, see authorize_admin)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+
+    /// Approved proposal for this call (council mode only)
+    #[account(mut)]
+    pub admin_proposal: Option<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<SweepUnclaimed>) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.admin_council.as_ref(),
+        ctx.accounts.admin_proposal.as_mut(),
+        &AdminAction::SweepUnclaimed { market: ctx.accounts.market.key() },
+    )?;
+
+    let market = &mut ctx.accounts.market;
+
+    // -------------------------
+    // 1) Claim window must be over
+    // -------------------------
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now > market.effective_claim_deadline(),
+        ErrorCode::ClaimPeriodNotOver
+    );
+
+    // -------------------------
+    // 2) Sweep unclaimed SOL (NoWins / Refund)
+    // -------------------------
+    // For YesWins, pool_balance tracks the vault's reserved rent and the market
+    // account holds founder vesting SOL, so only unclaimed clawback SOL is swept.
+
+    let mut swept_sol = 0u64;
+    let (unclaimed_clawback_tokens, unclaimed_clawback_sol) = market.clawback_unclaimed();
+
+    if market.resolution != MarketResolution::YesWins && market.pool_balance > 0 {
+        // Never dip into the market account's own rent-exempt minimum
+        let rent = Rent::get()?;
+        let market_info = market.to_account_info();
+        let rent_exempt = rent.minimum_balance(market_info.data_len());
+        let available = market_info.lamports().saturating_sub(rent_exempt);
+
+        swept_sol = std::cmp::min(market.pool_balance, available);
+
+        if swept_sol > 0 {
+            **market_info.try_borrow_mut_lamports()? -= swept_sol;
+            **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += swept_sol;
+        }
+
+        market.pool_balance = market
+            .pool_balance
+            .checked_sub(swept_sol)
+            .ok_or(ErrorCode::MathError)?;
+    }
+
+    if unclaimed_clawback_sol > 0 {
+        // Clawed back SOL sits in the market account next to founder vesting SOL
+        let market_info = market.to_account_info();
+        require!(
+            market_info.lamports() >= unclaimed_clawback_sol,
+            ErrorCode::InsufficientBalance
+        );
+
+        **market_info.try_borrow_mut_lamports()? -= unclaimed_clawback_sol;
+        **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += unclaimed_clawback_sol;
+
+        swept_sol = swept_sol
+            .checked_add(unclaimed_clawback_sol)
+            .ok_or(ErrorCode::MathError)?;
+    }
+
+    // -------------------------
+    // 3) Sweep unclaimed YES voter and clawed back tokens (YesWins)
+    // -------------------------
+
+    let mut swept_tokens = 0u64;
+
+    if market.resolution == MarketResolution::YesWins {
+        let unclaimed_tokens = market
+            .yes_voter_tokens_allocated
+            .saturating_sub(market.yes_voter_tokens_claimed)
+            .checked_add(unclaimed_clawback_tokens)
+            .ok_or(ErrorCode::MathError)?;
+
+        if unclaimed_tokens > 0 {
+            let (Some(market_token_account), Some(treasury_token_account), Some(token_mint), Some(token_program)) = (
+                ctx.accounts.market_token_account.as_ref(),
+                ctx.accounts.treasury_token_account.as_ref(),
+                ctx.accounts.token_mint.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return Err(ErrorCode::InvalidAccountData.into());
+            };
+
+            require!(
+                Some(token_mint.key()) == market.token_mint,
+                ErrorCode::Unauthorized
+            );
+
+            // Create PDA signer seeds for market account
+            let founder_key = market.founder;
+            let ipfs_hash = anchor_lang::solana_program::hash::hash(market.ipfs_cid.as_bytes());
+            let market_seeds = &[
+                b"market",
+                founder_key.as_ref(),
+                ipfs_hash.as_ref(),
+                &[market.bump],
+            ];
+            let signer_seeds = &[&market_seeds[..]];
+
+            let transfer_ctx = CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: market_token_account.to_account_info(),
+                    to: treasury_token_account.to_account_info(),
+                    authority: market.to_account_info(),
+                    mint: token_mint.to_account_info(),
+                },
+                signer_seeds,
+            );
+
+            token_interface::transfer_checked(transfer_ctx, unclaimed_tokens, token_mint.decimals)?;
+
+            swept_tokens = unclaimed_tokens;
+        }
+    }
+
+    // -------------------------
+    // 4) Record sweep and close claims
+    // -------------------------
+
+    market.unclaimed_swept = true;
+    market.swept_sol = swept_sol;
+    market.swept_tokens = swept_tokens;
+
+    msg!("🧹 Unclaimed funds swept to treasury");
+    msg!("   Market: {}", market.key());
+    msg!("   SOL: {} lamports", swept_sol);
+    msg!("   Tokens: {}", swept_tokens);
+
+    Ok(())
+}
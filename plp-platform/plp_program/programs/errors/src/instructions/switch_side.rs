@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::amm::*;
+
+/// Switch an existing position to the opposite side
+///
+/// Flow:
+/// 1. Validate market is active, not expired and still in Prediction phase
+/// 2. Sell the user's current shares back into the AMM (no fee on the sell leg)
+/// 3. Deduct one 1.5% trade fee from the proceeds → treasury (paid from vault)
+/// 4. Buy the opposite side with the remaining proceeds
+/// 5. Update position shares, market share totals and AMM pools
+///
+/// No SOL enters or leaves the user's wallet; only the fee leaves the vault.
+/// total_invested is reduced so refunds (98.5% of total_invested) still
+/// match the user's SOL remaining in the vault.
+#[derive(Accounts)]
+pub struct SwitchSide<'info> {
+    #[account(
+        mut,
+        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved,
+        constraint = market.phase == MarketPhase::Prediction @ ErrorCode::InvalidMarketPhase,
+        constraint = !market.outcome_tokens_enabled @ ErrorCode::OutcomeTokensEnabled,
+        constraint = !market.is_paused(PAUSE_BUY_YES | PAUSE_BUY_NO) @ ErrorCode::InstructionPaused
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Market Vault PDA (holds all SOL for the market)
+    #[account(
+        mut,
+        seeds = [b"market_vault", market.key().as_ref()],
+        bump
+    )]
+    pub market_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        constraint = position.user == user.key() @ ErrorCode::Unauthorized,
+        constraint = position.market == market.key() @ ErrorCode::Unauthorized
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        constraint = !treasury.is_paused(PAUSE_BUY_YES | PAUSE_BUY_NO) @ ErrorCode::InstructionPaused
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SwitchSide>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let position = &mut ctx.accounts.position;
+
+    // -------------------------
+    // 1) Validation checks
+    // -------------------------
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < market.expiry_time, ErrorCode::MarketExpired);
+
+    // Exactly one side is held (one position per wallet)
+    let from_yes = position.yes_shares > 0;
+    let old_shares = if from_yes { position.yes_shares } else { position.no_shares };
+    require!(old_shares > 0, ErrorCode::InsufficientBalance);
+
+    // -------------------------
+    // 2) Sell current side back into the AMM
+    // -------------------------
+
+    let sol_out = calculate_sol_from_shares(
+        market.yes_pool,
+        market.no_pool,
+        old_shares,
+        from_yes,
+    )?;
+
+    require!(sol_out > 0, ErrorCode::MathError);
+
+    if from_yes {
+        // Selling YES: YES pool increases (shares returned), NO pool decreases (SOL removed)
+        market.yes_pool = market
+            .yes_pool
+            .checked_add(old_shares)
+            .ok_or(ErrorCode::MathError)?;
+        market.no_pool = market
+            .no_pool
+            .checked_sub(sol_out)
+            .ok_or(ErrorCode::MathError)?;
+        market.total_yes_shares = market
+            .total_yes_shares
+            .checked_sub(old_shares)
+            .ok_or(ErrorCode::MathError)?;
+    } else {
+        // Selling NO: NO pool increases (shares returned), YES pool decreases (SOL removed)
+        market.no_pool = market
+            .no_pool
+            .checked_add(old_shares)
+            .ok_or(ErrorCode::MathError)?;
+        market.yes_pool = market
+            .yes_pool
+            .checked_sub(sol_out)
+            .ok_or(ErrorCode::MathError)?;
+        market.total_no_shares = market
+            .total_no_shares
+            .checked_sub(old_shares)
+            .ok_or(ErrorCode::MathError)?;
+    }
+
+    // -------------------------
+    // 3) Charge a single trade fee on the switched amount
+    // -------------------------
+
+    let trade_fee = (sol_out * TRADE_FEE_BPS) / BPS_DIVISOR;
+    let net_amount = sol_out
+        .checked_sub(trade_fee)
+        .ok_or(ErrorCode::MathError)?;
+
+    require!(net_amount > 0, ErrorCode::InvestmentTooSmall);
+
+    // Fee comes out of the user's SOL already held in the vault
+    let market_key = market.key();
+    let vault_seeds = &[
+        b"market_vault",
+        market_key.as_ref(),
+        &[ctx.bumps.market_vault],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.market_vault.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        trade_fee,
+    )?;
+
+    // Update treasury fee ledger and market fee counter
+    ctx.accounts.treasury.record_fee(FeeSource::Trade, trade_fee)?;
+    market.trade_fees_generated = market
+        .trade_fees_generated
+        .checked_add(trade_fee)
+        .ok_or(ErrorCode::MathError)?;
+
+    // Update market pool balance tracker
+    market.pool_balance = market
+        .pool_balance
+        .checked_sub(trade_fee)
+        .ok_or(ErrorCode::MathError)?;
+
+    // -------------------------
+    // 4) Buy the opposite side with the net proceeds
+    // -------------------------
+
+    let new_shares = calculate_shares_from_sol(
+        market.yes_pool,
+        market.no_pool,
+        net_amount,
+        !from_yes,
+    )?;
+
+    require!(new_shares > 0, ErrorCode::MathError);
+
+    if from_yes {
+        // Buying NO: NO pool decreases (shares removed), YES pool increases (SOL added)
+        market.no_pool = market
+            .no_pool
+            .checked_sub(new_shares)
+            .ok_or(ErrorCode::MathError)?;
+        market.yes_pool = market
+            .yes_pool
+            .checked_add(net_amount)
+            .ok_or(ErrorCode::MathError)?;
+        market.total_no_shares = market
+            .total_no_shares
+            .checked_add(new_shares)
+            .ok_or(ErrorCode::MathError)?;
+    } else {
+        // Buying YES: YES pool decreases (shares removed), NO pool increases (SOL added)
+        market.yes_pool = market
+            .yes_pool
+            .checked_sub(new_shares)
+            .ok_or(ErrorCode::MathError)?;
+        market.no_pool = market
+            .no_pool
+            .checked_add(net_amount)
+            .ok_or(ErrorCode::MathError)?;
+        market.total_yes_shares = market
+            .total_yes_shares
+            .checked_add(new_shares)
+            .ok_or(ErrorCode::MathError)?;
+    }
+
+    // -------------------------
+    // 5) Update position
+    // -------------------------
+
+    if from_yes {
+        position.yes_shares = 0;
+        position.no_shares = new_shares;
+    } else {
+        position.no_shares = 0;
+        position.yes_shares = new_shares;
+    }
+
+    // Refunds pay 98.5% of total_invested, so remove the gross amount
+    // whose net equals the fee that just left the vault
+    let invested_reduction = (trade_fee as u128 * BPS_DIVISOR as u128
+        / (BPS_DIVISOR - TRADE_FEE_BPS) as u128) as u64;
+    position.total_invested = position.total_invested.saturating_sub(invested_reduction);
+
+    // -------------------------
+    // 6) Anti-sniping soft close
+    // -------------------------
+
+    let new_expiry = market.soft_close_expiry(now);
+    if new_expiry > market.expiry_time {
+        msg!("⏱️  Late trade - expiry extended from {} to {}", market.expiry_time, new_expiry);
+        market.expiry_time = new_expiry;
+    }
+
+    msg!(
+        "🔄 Switched {} {} shares → {} {} shares (fee {} lamports)",
+        old_shares,
+        if from_yes { "YES" } else { "NO" },
+        new_shares,
+        if from_yes { "NO" } else { "YES" },
+        trade_fee
+    );
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Transfer a position to another wallet (e.g. hot wallet → hardware wallet)
+///
+/// Flow:
+/// 1. Validate market is still unresolved
+/// 2. Check one-position rule against the recipient's existing position
+/// 3. Move shares and total_invested into the recipient's position PDA
+///    (created if needed, paid by the sender)
+/// 4. Close the sender's position and refund its rent
+///
+/// Transfers are refused after resolution so claims stay tied to a single position.
+/// The track record moves with the position: its outcome is recorded in the
+/// recipient's Reputation when it settles, not the sender's.
+/// Outcome token markets transfer the SPL tokens instead.
+#[derive(Accounts)]
+pub struct TransferPosition<'info> {
+    #[account(
+        constraint = market.resolution == MarketResolution::Unresolved @ ErrorCode::AlreadyResolved,
+        constraint = !market.outcome_tokens_enabled @ ErrorCode::OutcomeTokensEnabled
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        constraint = position.user == user.key() @ ErrorCode::Unauthorized,
+        constraint = position.market == market.key() @ ErrorCode::Unauthorized,
+        close = user  // 🔥 Close source account and send rent to sender
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = Position::SPACE,
+        seeds = [b"position", market.key().as_ref(), recipient.key().as_ref()],
+        bump,
+        constraint = recipient_position.user == Pubkey::default() || recipient_position.user == recipient.key() @ ErrorCode::Unauthorized,
+        constraint = recipient_position.market == Pubkey::default() || recipient_position.market == market.key() @ ErrorCode::Unauthorized
+    )]
+    pub recipient_position: Account<'info, Position>,
+
+    /// Wallet receiving the position
+    /// CHECK: Any wallet can receive a position; only used as a PDA seed
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Current position owner
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<TransferPosition>) -> Result<()> {
+    let position = &mut ctx.accounts.position;
+    let recipient_position = &mut ctx.accounts.recipient_position;
+
+    // -------------------------
+    // 1) Validation checks
+    // -------------------------
+
+    require!(
+        ctx.accounts.recipient.key() != ctx.accounts.user.key(),
+        ErrorCode::Unauthorized
+    );
+
+    require!(
+        position.yes_shares > 0 || position.no_shares > 0,
+        ErrorCode::InsufficientBalance
+    );
+
+    // One position rule: recipient cannot hold the opposite side
+    require!(
+        position.yes_shares == 0 || recipient_position.no_shares == 0,
+        ErrorCode::AlreadyHasPosition
+    );
+    require!(
+        position.no_shares == 0 || recipient_position.yes_shares == 0,
+        ErrorCode::AlreadyHasPosition
+    );
+
+    // -------------------------
+    // 2) Initialize recipient position if needed
+    // -------------------------
+
+    if recipient_position.user == Pubkey::default() {
+        recipient_position.user = ctx.accounts.recipient.key();
+        recipient_position.market = ctx.accounts.market.key();
+        recipient_position.yes_shares = 0;
+        recipient_position.no_shares = 0;
+        recipient_position.total_invested = 0;
+        recipient_position.claimed = false;
+        recipient_position.bump = ctx.bumps.recipient_position;
+    }
+
+    // -------------------------
+    // 3) Move shares and invested amount
+    // -------------------------
+
+    recipient_position.yes_shares = recipient_position
+        .yes_shares
+        .checked_add(position.yes_shares)
+        .ok_or(ErrorCode::MathError)?;
+
+    recipient_position.no_shares = recipient_position
+        .no_shares
+        .checked_add(position.no_shares)
+        .ok_or(ErrorCode::MathError)?;
+
+    recipient_position.total_invested = recipient_position
+        .total_invested
+        .checked_add(position.total_invested)
+        .ok_or(ErrorCode::MathError)?;
+
+    msg!("📦 Position transferred");
+    msg!("   From: {}", ctx.accounts.user.key());
+    msg!("   To: {}", ctx.accounts.recipient.key());
+    msg!("   YES shares: {}", position.yes_shares);
+    msg!("   NO shares: {}", position.no_shares);
+    msg!("   Invested: {} lamports", position.total_invested);
+
+    position.yes_shares = 0;
+    position.no_shares = 0;
+    position.total_invested = 0;
+
+    // The `close = user` constraint closes the source position after this returns
+
+    Ok(())
+}
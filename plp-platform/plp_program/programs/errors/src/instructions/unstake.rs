@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Withdraw staked platform tokens
+///
+/// Rewards earned up to now stay claimable via claim_staking_rewards.
+/// Rejected until STAKE_LOCK_PERIOD has passed since the position's last stake.
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_pool"],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", owner.key().as_ref()],
+        bump = stake_position.bump,
+        constraint = stake_position.owner == owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    #[account(
+        mut,
+        address = staking_pool.stake_vault @ ErrorCode::Unauthorized
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Owner's platform token account (receives unstaked tokens)
+    #[account(
+        mut,
+        token::mint = stake_mint,
+        token::authority = owner,
+        token::token_program = token_program
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = staking_pool.stake_mint @ ErrorCode::Unauthorized,
+        mint::token_program = token_program
+    )]
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let stake_position = &mut ctx.accounts.stake_position;
+
+    require!(
+        amount > 0 && amount <= stake_position.amount,
+        ErrorCode::InvalidStakeAmount
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= stake_position.locked_until,
+        ErrorCode::StakeLocked
+    );
+
+    // -------------------------
+    // 1) Settle rewards earned on the current amount
+    // -------------------------
+
+    stake_position.settle(staking_pool.reward_per_share)?;
+
+    // -------------------------
+    // 2) Transfer tokens out of the stake vault (staking pool PDA signs)
+    // -------------------------
+
+    let pool_seeds = &[b"staking_pool".as_ref(), &[staking_pool.bump]];
+    let signer_seeds = &[&pool_seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.stake_vault.to_account_info(),
+            to: ctx.accounts.owner_token_account.to_account_info(),
+            authority: staking_pool.to_account_info(),
+            mint: ctx.accounts.stake_mint.to_account_info(),
+        },
+        signer_seeds,
+    );
+
+    token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.stake_mint.decimals)?;
+
+    // -------------------------
+    // 3) Update balances
+    // -------------------------
+
+    stake_position.amount = stake_position
+        .amount
+        .checked_sub(amount)
+        .ok_or(ErrorCode::MathError)?;
+    stake_position.reset_debt(staking_pool.reward_per_share)?;
+
+    staking_pool.total_staked = staking_pool
+        .total_staked
+        .checked_sub(amount)
+        .ok_or(ErrorCode::MathError)?;
+
+    msg!("🥩 Unstaked {} tokens", amount);
+    msg!("   Owner: {}", stake_position.owner);
+    msg!("   Position: {} / pool total {}", stake_position.amount, staking_pool.total_staked);
+    msg!("   Unclaimed rewards: {} lamports", stake_position.unclaimed);
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Change admin council members and threshold (approved UpdateCouncil proposal required)
+///
+/// Approvals on other pending proposals only count for members still on the council.
+#[derive(Accounts)]
+pub struct UpdateAdminCouncil<'info> {
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+
+    #[account(mut)]
+    pub admin_proposal: Account<'info, AdminProposal>,
+
+    /// Council member executing the proposal
+    pub member: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateAdminCouncil>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    AdminCouncil::validate(&members, threshold)?;
+
+    // Council changes always go through a proposal, even outside council mode
+    require!(
+        ctx.accounts.treasury.admin == ctx.accounts.admin_council.key(),
+        ErrorCode::Unauthorized
+    );
+    authorize_admin(
+        &ctx.accounts.treasury,
+        &ctx.accounts.member.key(),
+        Some(&ctx.accounts.admin_council),
+        Some(&mut ctx.accounts.admin_proposal),
+        &AdminAction::UpdateCouncil { members: members.clone(), threshold },
+    )?;
+
+    let admin_council = &mut ctx.accounts.admin_council;
+    admin_council.members = members;
+    admin_council.threshold = threshold;
+
+    msg!("🏛️  Admin council updated");
+    msg!("   Members: {}", admin_council.members.len());
+    msg!("   Threshold: {}", threshold);
+
+    Ok(())
+}
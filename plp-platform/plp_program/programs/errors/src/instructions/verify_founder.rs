@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Set or revoke a founder's verification (founder verifier, admin or council member)
+#[derive(Accounts)]
+pub struct VerifyFounder<'info> {
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"founder_profile", founder_profile.founder.as_ref()],
+        bump = founder_profile.bump
+    )]
+    pub founder_profile: Account<'info, FounderProfile>,
+
+    /// Treasury.founder_verifier, the admin, or a council member in council mode
+    pub verifier: Signer<'info>,
+
+    /// Admin council (council mode only)
+    #[account(
+        seeds = [b"admin_council"],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Option<Account<'info, AdminCouncil>>,
+}
+
+pub fn handler(ctx: Context<VerifyFounder>, verified: bool) -> Result<()> {
+    let treasury = &ctx.accounts.treasury;
+    let verifier = ctx.accounts.verifier.key();
+
+    let is_verifier = treasury.founder_verifier != Pubkey::default()
+        && treasury.founder_verifier == verifier;
+    if !is_verifier {
+        authorize_admin_or_member(treasury, &verifier, ctx.accounts.admin_council.as_ref())?;
+    }
+
+    let founder_profile = &mut ctx.accounts.founder_profile;
+    require!(
+        founder_profile.founder != verifier,
+        ErrorCode::Unauthorized
+    );
+
+    founder_profile.verified = verified;
+    founder_profile.verified_by = verifier;
+    founder_profile.verified_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "🪪 Founder {} {}",
+        founder_profile.founder,
+        if verified { "verified" } else { "unverified" }
+    );
+    msg!("   By: {}", verifier);
+
+    Ok(())
+}
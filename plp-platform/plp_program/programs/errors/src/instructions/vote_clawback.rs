@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Vote to claw back unvested founder SOL and team tokens (YES holders)
+///
+/// For funded projects the founder has abandoned. Each YES holder votes once,
+/// weighted by their position's yes_shares (a clawback_vote PDA prevents double
+/// voting). Once votes exceed half of market.total_yes_shares, anyone can run
+/// execute_clawback.
+///
+/// YesWins positions stay open after claim_rewards, so holders who already
+/// claimed their voter tokens can still vote.
+#[derive(Accounts)]
+pub struct VoteClawback<'info> {
+    #[account(
+        mut,
+        constraint = market.resolution == MarketResolution::YesWins @ ErrorCode::InvalidResolutionState,
+        constraint = market.clawback_executed_at == 0 @ ErrorCode::ClawbackAlreadyExecuted
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Voter's position (weight = yes_shares)
+    #[account(
+        seeds = [b"position", market.key().as_ref(), voter.key().as_ref()],
+        bump = position.bump,
+        constraint = position.user == voter.key() @ ErrorCode::Unauthorized
+    )]
+    pub position: Account<'info, Position>,
+
+    /// Vote record (creation fails if the holder already voted)
+    #[account(
+        init,
+        payer = voter,
+        space = ClawbackVote::SPACE,
+        seeds = [b"clawback_vote", market.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub clawback_vote: Account<'info, ClawbackVote>,
+
+    /// YES holder voting (pays for the vote record)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<VoteClawback>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let position = &ctx.accounts.position;
+    let clawback_vote = &mut ctx.accounts.clawback_vote;
+
+    require!(position.yes_shares > 0, ErrorCode::InsufficientBalance);
+
+    clawback_vote.voter = ctx.accounts.voter.key();
+    clawback_vote.weight = position.yes_shares;
+    clawback_vote.bump = ctx.bumps.clawback_vote;
+
+    market.clawback_votes = market
+        .clawback_votes
+        .checked_add(position.yes_shares)
+        .ok_or(ErrorCode::MathError)?;
+
+    msg!("🗳️  Clawback vote by {}", ctx.accounts.voter.key());
+    msg!("   Votes for: {} / {} YES shares", market.clawback_votes, market.total_yes_shares);
+
+    if market.clawback_approved() {
+        msg!("✅ Clawback approved by YES holder majority");
+    }
+
+    Ok(())
+}
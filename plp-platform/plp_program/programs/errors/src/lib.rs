@@ -2,11 +2,13 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
 
 use instructions::*;
+use state::{AdminAction, BondSlashDestination, RevokeDestination, VestingSchedule};
 
 // 🔐 Program ID for mainnet/devnet deployment (same ID for both networks)
 declare_id!("C5mVE2BwSehWJNkNvhpsoepyKwZkvSLZx29bi4MzVj86");
@@ -29,9 +31,105 @@ pub mod plp_prediction_market {
         instructions::set_admin::handler(ctx, new_admin)
     }
 
-    /// Withdraw platform fees from Treasury PDA to a recipient wallet
-    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
-        instructions::withdraw_fees::handler(ctx, amount)
+    /// Change the claim window applied to newly resolved markets (admin only)
+    ///
+    /// Args:
+    /// - claim_period: Seconds after resolution before unclaimed funds can be swept (7-365 days)
+    pub fn set_claim_period(ctx: Context<SetClaimPeriod>, claim_period: i64) -> Result<()> {
+        instructions::set_claim_period::handler(ctx, claim_period)
+    }
+
+    /// Pause individual instructions platform-wide or for one market (admin only)
+    ///
+    /// Args:
+    /// - paused: PAUSE_* bitmask (create_market, buy_yes, buy_no, extend_market, resolve_market), 0 = unpause
+    pub fn set_pause(ctx: Context<SetPause>, paused: u8) -> Result<()> {
+        instructions::set_pause::handler(ctx, paused)
+    }
+
+    /// Set the treasury withdrawal policy (admin only)
+    ///
+    /// Args:
+    /// - withdrawal_delay: Timelock between queue and execute in seconds (1 hour - 30 days)
+    /// - epoch_cap: Max lamports withdrawn per Solana epoch (0 = no cap)
+    /// - allowlist: Allowed recipient wallets (max 5)
+    pub fn configure_withdrawals(
+        ctx: Context<ConfigureWithdrawals>,
+        withdrawal_delay: i64,
+        epoch_cap: u64,
+        allowlist: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::configure_withdrawals::handler(ctx, withdrawal_delay, epoch_cap, allowlist)
+    }
+
+    /// Queue a withdrawal of platform fees to an allowlisted recipient (admin only)
+    pub fn queue_withdrawal(ctx: Context<QueueWithdrawal>, amount: u64) -> Result<()> {
+        instructions::queue_withdrawal::handler(ctx, amount)
+    }
+
+    /// Execute a queued withdrawal once its timelock has passed (admin only, per-epoch cap applies)
+    ///
+    /// The stakers' share of fees not yet distributed cannot be withdrawn
+    pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
+        instructions::execute_withdrawal::handler(ctx)
+    }
+
+    /// Cancel a queued withdrawal (admin only)
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+        instructions::cancel_withdrawal::handler(ctx)
+    }
+
+    /// Change the wallet receiving platform token allocations (admin only)
+    ///
+    /// Args:
+    /// - recipient: New recipient wallet (Pubkey::default() = P&L wallet)
+    pub fn set_platform_recipient(ctx: Context<SetPlatformRecipient>, recipient: Pubkey) -> Result<()> {
+        instructions::set_platform_recipient::handler(ctx, recipient)
+    }
+
+    // ========================================
+    // ADMIN COUNCIL (MULTISIG)
+    // ========================================
+
+    /// Create the M-of-N admin council and make it the treasury admin (admin only)
+    ///
+    /// Admin-gated instructions then need an approved proposal for the exact call.
+    ///
+    /// Args:
+    /// - members: Council member wallets (1-10, unique)
+    /// - threshold: Approvals required per proposal (1 - members)
+    pub fn init_admin_council(
+        ctx: Context<InitAdminCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::init_admin_council::handler(ctx, members, threshold)
+    }
+
+    /// Propose an admin operation (council member only, counts as the first approval)
+    ///
+    /// Args:
+    /// - action: Admin instruction and arguments to authorize
+    pub fn propose_admin_action(ctx: Context<ProposeAdminAction>, action: AdminAction) -> Result<()> {
+        instructions::propose_admin_action::handler(ctx, action)
+    }
+
+    /// Approve a pending admin proposal (council member only)
+    pub fn approve_admin_action(ctx: Context<ApproveAdminAction>) -> Result<()> {
+        instructions::approve_admin_action::handler(ctx)
+    }
+
+    /// Replace council members and threshold (approved UpdateCouncil proposal required)
+    ///
+    /// Args:
+    /// - members: New council member wallets (1-10, unique)
+    /// - threshold: New approvals required per proposal
+    pub fn update_admin_council(
+        ctx: Context<UpdateAdminCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::update_admin_council::handler(ctx, members, threshold)
     }
 
     // ========================================
@@ -45,14 +143,19 @@ pub mod plp_prediction_market {
     /// - target_pool: Target SOL pool size (5/10/15 SOL in lamports)
     /// - expiry_time: Unix timestamp when market expires
     /// - metadata_uri: Full metadata URI for pump.fun (max 200 chars)
+    /// - team_schedule: Curve, cliff and duration for team token vesting
+    /// - founder_schedule: Curve, cliff and duration for founder excess SOL vesting
+    ///   (all-zero schedules use the default: 12 months linear, no cliff)
     ///
-    /// Charges 0.015 SOL creation fee to treasury
+    /// Charges 0.015 SOL creation fee to treasury and locks a 1% founder bond
     pub fn create_market(
         ctx: Context<CreateMarket>,
         ipfs_cid: String,
         target_pool: u64,
         expiry_time: i64,
         metadata_uri: String,
+        team_schedule: VestingSchedule,
+        founder_schedule: VestingSchedule,
     ) -> Result<()> {
         instructions::create_market::handler(
             ctx,
@@ -60,9 +163,106 @@ pub mod plp_prediction_market {
             target_pool,
             expiry_time,
             metadata_uri,
+            team_schedule,
+            founder_schedule,
+        )
+    }
+
+    /// Configure anti-sniping soft close (founder only, before first trade)
+    ///
+    /// Args:
+    /// - window: Seconds before expiry in which a trade extends the market (0 = disabled)
+    /// - extension: Seconds added to expiry_time per late trade
+    /// - hard_expiry_time: Unix timestamp the market can never be extended beyond
+    pub fn configure_soft_close(
+        ctx: Context<ConfigureSoftClose>,
+        window: i64,
+        extension: i64,
+        hard_expiry_time: i64,
+    ) -> Result<()> {
+        instructions::configure_soft_close::handler(ctx, window, extension, hard_expiry_time)
+    }
+
+    /// Enable YES/NO outcome SPL tokens for a market (founder only, before first trade)
+    ///
+    /// Creates YES/NO mint PDAs with the market as mint authority
+    /// buy_yes/buy_no then mint outcome tokens and claim_rewards burns them for payout
+    pub fn enable_outcome_tokens(ctx: Context<EnableOutcomeTokens>) -> Result<()> {
+        instructions::enable_outcome_tokens::handler(ctx)
+    }
+
+    /// Configure team token and founder SOL vesting schedules (founder only, before first trade)
+    ///
+    /// Schedules are set at create_market; this adjusts them and sets the governance options
+    ///
+    /// Args:
+    /// - team_schedule: Curve, cliff and duration for team token vesting
+    /// - founder_schedule: Curve, cliff and duration for founder excess SOL vesting
+    /// - revocable: Allow the platform admin to revoke unvested amounts (revoke_vesting)
+    /// - voter_vesting_duration: Stream YES voter tokens linearly over this many seconds (0 = off)
+    /// All-zero schedules keep the default (12 months linear, no cliff)
+    pub fn configure_vesting(
+        ctx: Context<ConfigureVesting>,
+        team_schedule: VestingSchedule,
+        founder_schedule: VestingSchedule,
+        revocable: bool,
+        voter_vesting_duration: i64,
+    ) -> Result<()> {
+        instructions::configure_vesting::handler(
+            ctx,
+            team_schedule,
+            founder_schedule,
+            revocable,
+            voter_vesting_duration,
         )
     }
 
+    /// Split team token vesting across beneficiaries (founder only, before first trade)
+    ///
+    /// Args:
+    /// - beneficiaries: Up to 10 (wallet, bps) entries summing to 10000 (empty = no split)
+    /// Each beneficiary claims their own share via claim_team_tokens
+    pub fn configure_team_split(
+        ctx: Context<ConfigureTeamSplit>,
+        beneficiaries: Vec<TeamSplitEntry>,
+    ) -> Result<()> {
+        instructions::configure_team_split::handler(ctx, beneficiaries)
+    }
+
+    /// Create or update the caller's founder profile (clears verification on change)
+    ///
+    /// Args:
+    /// - handle_hash: sha256 of the display handle
+    /// - website_hash: sha256 of the website URL
+    pub fn set_founder_profile(
+        ctx: Context<SetFounderProfile>,
+        handle_hash: [u8; 32],
+        website_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::set_founder_profile::handler(ctx, handle_hash, website_hash)
+    }
+
+    /// Verify or unverify a founder (founder verifier, admin or council member)
+    ///
+    /// Args:
+    /// - verified: New verification status
+    pub fn verify_founder(ctx: Context<VerifyFounder>, verified: bool) -> Result<()> {
+        instructions::verify_founder::handler(ctx, verified)
+    }
+
+    /// Set the founder verifier and the target pool requiring verification (admin only)
+    ///
+    /// Args:
+    /// - verifier: Wallet allowed to verify founders (Pubkey::default() = admin only)
+    /// - min_target_pool: Target pool (lamports) from which create_market needs a verified founder (0 = never)
+    pub fn configure_founder_verification(
+        ctx: Context<ConfigureFounderVerification>,
+        verifier: Pubkey,
+        min_target_pool: u64,
+    ) -> Result<()> {
+        instructions::configure_founder_verification::handler(ctx, verifier, min_target_pool)
+    }
+
     // ========================================
     // TRADING
     // ========================================
@@ -74,6 +274,7 @@ pub mod plp_prediction_market {
     ///
     /// Deducts 1.5% trade fee, calculates shares via LMSR
     /// Enforces one-position rule (cannot have NO shares)
+    /// Trades inside the soft-close window extend expiry_time
     pub fn buy_yes(ctx: Context<BuyYes>, sol_amount: u64) -> Result<()> {
         instructions::buy_yes::handler(ctx, sol_amount)
     }
@@ -85,10 +286,29 @@ pub mod plp_prediction_market {
     ///
     /// Deducts 1.5% trade fee, calculates shares via LMSR
     /// Enforces one-position rule (cannot have YES shares)
+    /// Trades inside the soft-close window extend expiry_time
     pub fn buy_no(ctx: Context<BuyNo>, sol_amount: u64) -> Result<()> {
         instructions::buy_no::handler(ctx, sol_amount)
     }
 
+    /// Switch an existing position to the opposite side
+    ///
+    /// Sells current shares back into the AMM and buys the other side atomically
+    /// Charges a single 1.5% trade fee on the switched amount
+    pub fn switch_side(ctx: Context<SwitchSide>) -> Result<()> {
+        instructions::switch_side::handler(ctx)
+    }
+
+    /// Transfer a position to another wallet
+    ///
+    /// Moves shares and invested amount into the recipient's position PDA
+    /// (one-position rule still applies) and closes the sender's position
+    /// Only allowed while the market is unresolved; the recipient inherits the
+    /// position's eventual Reputation outcome
+    pub fn transfer_position(ctx: Context<TransferPosition>) -> Result<()> {
+        instructions::transfer_position::handler(ctx)
+    }
+
     // ========================================
     // MARKET EXTENSION
     // ========================================
@@ -116,6 +336,8 @@ pub mod plp_prediction_market {
     /// - q_yes > q_no → YesWins (token launch, 5% fee)
     /// - q_no > q_yes → NoWins (SOL distribution, 5% fee)
     /// - Equal or insufficient → Refund (no fees)
+    ///
+    /// Counts the outcome in the founder's FounderReputation PDA
     pub fn resolve_market(ctx: Context<ResolveMarket>) -> Result<()> {
         instructions::resolve_market::handler(ctx)
     }
@@ -128,19 +350,22 @@ pub mod plp_prediction_market {
     /// - Refund: Full refund of invested amount
     ///
     /// Position PDA is automatically closed and rent refunded to user
+    /// Outcome token markets burn the user's outcome tokens and pay on the burned amount
+    /// Losing positions settle with no payout (position closed)
+    /// Records the settled position in the user's Reputation PDA
     pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
         instructions::claim_rewards::handler(ctx)
     }
 
     /// Initialize team vesting schedule after YES wins
     ///
-    /// Must be called after resolve_market when market resolves to YesWins
-    /// Sets up 12-month linear vesting for team's 33% token allocation
-    pub fn init_team_vesting(ctx: Context<InitTeamVesting>, total_token_supply: u64) -> Result<()> {
-        instructions::init_team_vesting::handler(ctx, total_token_supply)
+    /// Only needed when team_vesting was not passed to resolve_market
+    /// Sets up vesting for the team allocation recorded at resolution
+    pub fn init_team_vesting(ctx: Context<InitTeamVesting>) -> Result<()> {
+        instructions::init_team_vesting::handler(ctx)
     }
 
-    /// Claim vested team tokens (linear 12-month vesting)
+    /// Claim vested team tokens (per market.team_vesting_schedule)
     ///
     /// Allows the team to claim tokens based on vesting schedule
     /// Can be called multiple times as tokens unlock monthly
@@ -151,12 +376,13 @@ pub mod plp_prediction_market {
     /// Initialize founder SOL vesting schedule after YES wins with excess pool
     ///
     /// Must be called after resolve_market when pool > 50 SOL
-    /// Sets up 12-month linear vesting for founder's excess SOL (8% immediate + 92% vested)
+    /// Sets up vesting for founder's excess SOL (8% immediate + 92% vested)
+    /// Refused once the founder SOL was revoked or clawed back
     pub fn init_founder_vesting(ctx: Context<InitFounderVesting>) -> Result<()> {
         instructions::init_founder_vesting::handler(ctx)
     }
 
-    /// Claim vested founder SOL (linear 12-month vesting)
+    /// Claim vested founder SOL (per market.founder_vesting_schedule)
     ///
     /// Allows the founder to claim excess SOL based on vesting schedule
     /// Can be called multiple times as SOL unlocks over time
@@ -164,14 +390,95 @@ pub mod plp_prediction_market {
         instructions::claim_founder_sol::handler(ctx)
     }
 
+    /// Move future vesting unlocks to a new wallet (signed by current beneficiary)
+    ///
+    /// Rotates team_wallet, the signer's team split share and/or the founder SOL
+    /// beneficiary, for whichever of those accounts are passed
+    pub fn rotate_vesting_beneficiary(
+        ctx: Context<RotateVestingBeneficiary>,
+        new_beneficiary: Pubkey,
+    ) -> Result<()> {
+        instructions::rotate_vesting_beneficiary::handler(ctx, new_beneficiary)
+    }
+
+    /// Revoke unvested team tokens / founder SOL (admin only, revocable markets)
+    ///
+    /// Unlocks stop at the revocation time; already-unlocked amounts stay claimable
+    /// - destination: Treasury or YesVoters (team tokens → voter allocation,
+    ///   founder SOL → clawback SOL claimed via claim_clawback)
+    pub fn revoke_vesting(ctx: Context<RevokeVesting>, destination: RevokeDestination) -> Result<()> {
+        instructions::revoke_vesting::handler(ctx, destination)
+    }
+
+    /// Gate part of founder vested SOL behind milestones (founder only, before first trade)
+    ///
+    /// Args:
+    /// - milestone_bps: Share of the vested slice per milestone (up to 5, total <= 10000, empty = off)
+    pub fn configure_milestones(ctx: Context<ConfigureMilestones>, milestone_bps: Vec<u16>) -> Result<()> {
+        instructions::configure_milestones::handler(ctx, milestone_bps)
+    }
+
+    /// Appoint the reviewer who can attest a market's milestones (admin only)
+    pub fn set_milestone_reviewer(ctx: Context<SetMilestoneReviewer>, reviewer: Pubkey) -> Result<()> {
+        instructions::set_milestone_reviewer::handler(ctx, reviewer)
+    }
+
+    /// Attest a founder milestone (reviewer) or vote for it (YES position holder)
+    ///
+    /// A milestone is attested by the reviewer or once YES votes exceed half of total YES shares
+    /// Attested milestone SOL then follows the founder vesting schedule
+    pub fn attest_milestone(ctx: Context<AttestMilestone>, milestone_index: u8) -> Result<()> {
+        instructions::attest_milestone::handler(ctx, milestone_index)
+    }
+
+    /// Vote to claw back unvested founder SOL and team tokens (YES holders, weighted by yes_shares)
+    pub fn vote_clawback(ctx: Context<VoteClawback>) -> Result<()> {
+        instructions::vote_clawback::handler(ctx)
+    }
+
+    /// Claw back unvested founder SOL and team tokens once YES votes pass 50% (permissionless)
+    ///
+    /// If the founder never initialized SOL vesting, all excess SOL is clawed back
+    pub fn execute_clawback(ctx: Context<ExecuteClawback>) -> Result<()> {
+        instructions::execute_clawback::handler(ctx)
+    }
+
+    /// Claim a pro-rata share of clawed back SOL and tokens (YES holders)
+    pub fn claim_clawback(ctx: Context<ClaimClawback>) -> Result<()> {
+        instructions::claim_clawback::handler(ctx)
+    }
+
     /// Claim platform's 2% token allocation (immediate, no vesting)
     ///
-    /// Transfers tokens to P&L wallet: 3MihVtsLsVuEccpmz4YG72Cr8CJWf1evRorTPdPiHeEQ
+    /// Transfers tokens to the platform recipient (default P&L wallet: 3MihVtsLsVuEccpmz4YG72Cr8CJWf1evRorTPdPiHeEQ)
     /// Can be called by anyone after token launch
     pub fn claim_platform_tokens(ctx: Context<ClaimPlatformTokens>) -> Result<()> {
         instructions::claim_platform_tokens::handler(ctx)
     }
 
+    /// Claim platform token allocations for many markets at once (permissionless)
+    ///
+    /// remaining_accounts: (market, market token account, recipient token account, mint) per market
+    pub fn claim_platform_tokens_batch<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimPlatformTokensBatch<'info>>) -> Result<()> {
+        instructions::claim_platform_tokens_batch::handler(ctx)
+    }
+
+    /// Return the founder's creation bond after resolution, launch steps and the lock period (permissionless)
+    pub fn release_founder_bond(ctx: Context<ReleaseFounderBond>) -> Result<()> {
+        instructions::release_founder_bond::handler(ctx)
+    }
+
+    /// Slash a founder's creation bond to the treasury or YES voters (admin only)
+    ///
+    /// Args:
+    /// - destination: Treasury, or YesVoters (YesWins, claimed via claim_clawback)
+    pub fn slash_founder_bond(
+        ctx: Context<SlashFounderBond>,
+        destination: BondSlashDestination,
+    ) -> Result<()> {
+        instructions::slash_founder_bond::handler(ctx, destination)
+    }
+
     // ========================================
     // ACCOUNT CLEANUP (RENT RECOVERY)
     // ========================================
@@ -180,6 +487,8 @@ pub mod plp_prediction_market {
     ///
     /// Allows users to close their position and recover the PDA rent (~0.002 SOL)
     /// Can be called after rewards are claimed, or in refund scenarios
+    /// (unclaimed refunds are recorded in the user's Reputation)
+    /// YesWins positions stay open while founder milestones are unattested
     pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
         instructions::close_position::handler(ctx)
     }
@@ -188,7 +497,7 @@ pub mod plp_prediction_market {
     ///
     /// Allows founder to close the market PDA after:
     /// - Market is resolved
-    /// - Claim period has ended (30 days after expiry)
+    /// - Claim deadline has passed (stamped at resolution)
     /// - Pool balance is empty
     ///
     /// Recovers market rent (~0.01 SOL) to founder
@@ -196,13 +505,45 @@ pub mod plp_prediction_market {
         instructions::close_market::handler(ctx)
     }
 
-    /// Emergency drain vault to founder (platform admin only)
+    /// Batch-process NoWins / Refund claims and settle losing positions (permissionless)
     ///
-    /// Drains all SOL from market vault (minus rent-exempt) to market founder
-    /// Works with both program-owned and system-owned vaults
-    /// Only callable by platform admin (treasury.admin) for emergency recovery
-    pub fn emergency_drain_vault(ctx: Context<EmergencyDrainVault>) -> Result<()> {
-        instructions::emergency_drain_vault::handler(ctx)
+    /// remaining_accounts: (position, owner wallet, owner Reputation) triples, all writable
+    /// Pays each owner directly, records the outcome in their Reputation
+    /// (created by the caller if missing) and closes the position (rent to owner)
+    /// Lets operators sweep markets left with dead-wallet positions
+    pub fn crank_claims<'info>(ctx: Context<'_, '_, 'info, 'info, CrankClaims<'info>>) -> Result<()> {
+        instructions::crank_claims::handler(ctx)
+    }
+
+    /// Sweep unclaimed funds to treasury after the claim deadline (admin only)
+    ///
+    /// NoWins/Refund: remaining pool SOL → treasury
+    /// YesWins: unclaimed YES voter and clawed back tokens → treasury token account,
+    /// unclaimed clawed back SOL → treasury
+    /// Records swept amounts on the market and closes further claims
+    pub fn sweep_unclaimed(ctx: Context<SweepUnclaimed>) -> Result<()> {
+        instructions::sweep_unclaimed::handler(ctx)
+    }
+
+    /// Close the market token account once distribution completes (permissionless)
+    ///
+    /// Requires platform, YES voter (claimed or swept) and team allocations settled
+    /// Moves any rounding remainder to the treasury token account, closes the account, rent → founder
+    /// Must run before close_market on YesWins markets
+    pub fn close_market_token_account(ctx: Context<CloseMarketTokenAccount>) -> Result<()> {
+        instructions::close_market_token_account::handler(ctx)
+    }
+
+    /// Force an unresolved market into Refund (platform admin only)
+    ///
+    /// Moves all vault SOL (minus rent-exempt) into the market and sets resolution = Refund
+    /// Every position then reclaims total_invested less fees via claim_rewards
+    /// Emits EmergencyRefundEvent with the reason
+    ///
+    /// Args:
+    /// - reason: Why the refund was triggered (1-200 bytes)
+    pub fn emergency_refund(ctx: Context<EmergencyRefund>, reason: String) -> Result<()> {
+        instructions::emergency_refund::handler(ctx, reason)
     }
 
     /// Migrate old Market accounts to new version with founder vesting fields
@@ -213,6 +554,67 @@ pub mod plp_prediction_market {
         instructions::migrate_market_v2::handler(ctx)
     }
 
+    /// Grow v2 Market accounts to the current layout
+    ///
+    /// Reallocates market account from 480 bytes → Market::SPACE (zero-filled)
+    /// Callable by anyone, permissionless migration
+    pub fn migrate_market_v3(ctx: Context<MigrateMarketV3>) -> Result<()> {
+        instructions::migrate_market_v3::handler(ctx)
+    }
+
+    /// Grow the Treasury PDA to the current layout (admin, or any council member)
+    ///
+    /// Must be run once after upgrading, before other treasury instructions
+    pub fn migrate_treasury(ctx: Context<MigrateTreasury>) -> Result<()> {
+        instructions::migrate_treasury::handler(ctx)
+    }
+
+    // ========================================
+    // PLATFORM TOKEN STAKING
+    // ========================================
+
+    /// Create the staking pool for the platform token (admin only, one-time)
+    ///
+    /// Args:
+    /// - staker_share_bps: Share of future treasury fee inflows paid to stakers (0-10000)
+    pub fn init_staking_pool(ctx: Context<InitStakingPool>, staker_share_bps: u16) -> Result<()> {
+        instructions::init_staking_pool::handler(ctx, staker_share_bps)
+    }
+
+    /// Change the stakers' share of treasury fee inflows (admin only)
+    ///
+    /// Args:
+    /// - staker_share_bps: New share in basis points (0-10000)
+    pub fn set_staker_share(ctx: Context<SetStakerShare>, staker_share_bps: u16) -> Result<()> {
+        instructions::set_staker_share::handler(ctx, staker_share_bps)
+    }
+
+    /// Stake platform tokens (locks the whole position for 7 days)
+    ///
+    /// Args:
+    /// - amount: Tokens to stake (base units)
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        instructions::stake::handler(ctx, amount)
+    }
+
+    /// Unstake platform tokens once the stake lock has passed (accrued rewards stay claimable)
+    ///
+    /// Args:
+    /// - amount: Tokens to unstake (base units)
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        instructions::unstake::handler(ctx, amount)
+    }
+
+    /// Move the stakers' share of new treasury fees into the staking pool (permissionless)
+    pub fn distribute_staking_rewards(ctx: Context<DistributeStakingRewards>) -> Result<()> {
+        instructions::distribute_staking_rewards::handler(ctx)
+    }
+
+    /// Pay a staker's accrued SOL rewards to their wallet (permissionless)
+    pub fn claim_staking_rewards(ctx: Context<ClaimStakingRewards>) -> Result<()> {
+        instructions::claim_staking_rewards::handler(ctx)
+    }
+
     // ========================================
     // LEGACY INSTRUCTIONS (DEPRECATED & REMOVED)
     // ========================================
@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_COUNCIL_MEMBERS;
+use crate::errors::ErrorCode;
+use super::{BondSlashDestination, RevokeDestination, Treasury};
+
+/// Admin operation a council proposal authorizes (arguments must match exactly)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum AdminAction {
+    SetAdmin { new_admin: Pubkey },
+    SetClaimPeriod { claim_period: i64 },
+    SetPause { market: Option<Pubkey>, paused: u8 },
+    ConfigureWithdrawals { withdrawal_delay: i64, epoch_cap: u64, allowlist: Vec<Pubkey> },
+    QueueWithdrawal { recipient: Pubkey, amount: u64 },
+    CancelWithdrawal { id: u64 },
+    EmergencyRefund { market: Pubkey, reason: String },
+    RevokeVesting { market: Pubkey, destination: RevokeDestination },
+    SetMilestoneReviewer { market: Pubkey, reviewer: Pubkey },
+    SweepUnclaimed { market: Pubkey },
+    UpdateCouncil { members: Vec<Pubkey>, threshold: u8 },
+    InitStakingPool { stake_mint: Pubkey, staker_share_bps: u16 },
+    SetStakerShare { staker_share_bps: u16 },
+    SetPlatformRecipient { recipient: Pubkey },
+    ConfigureFounderVerification { verifier: Pubkey, min_target_pool: u64 },
+    SlashFounderBond { market: Pubkey, destination: BondSlashDestination },
+}
+
+impl AdminAction {
+    /// Largest serialized action: 1 (variant) + UpdateCouncil (4 + 32 * members + 1 threshold)
+    pub const MAX_SIZE: usize = 1 + 4 + 32 * MAX_COUNCIL_MEMBERS + 1;
+}
+
+/// Native M-of-N admin council
+///
+/// PDA: ["admin_council"]. Created by init_admin_council, which also makes this
+/// PDA the Treasury.admin ("council mode"). From then on every admin-gated
+/// instruction needs an AdminProposal for exactly that call, approved by
+/// `threshold` current members (see authorize_admin).
+#[account]
+pub struct AdminCouncil {
+    /// Council members (max MAX_COUNCIL_MEMBERS)
+    pub members: Vec<Pubkey>,
+
+    /// Approvals required to execute a proposal
+    pub threshold: u8,
+
+    /// Id (PDA seed) of the next proposal
+    pub next_proposal_id: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AdminCouncil {
+    /// Space required for the account (in bytes)
+    pub const SPACE: usize = 8 + // discriminator
+        4 + 32 * MAX_COUNCIL_MEMBERS + // members vec
+        1 +  // threshold
+        8 +  // next_proposal_id
+        1;   // bump
+
+    /// Whether `key` is a current council member
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+
+    /// Approvals that still count (members removed since approving are ignored)
+    pub fn member_approvals(&self, approvals: &[Pubkey]) -> usize {
+        approvals.iter().filter(|a| self.is_member(a)).count()
+    }
+
+    /// Validate a member set and threshold (1-10 unique members, 1 <= threshold <= members)
+    pub fn validate(members: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= MAX_COUNCIL_MEMBERS,
+            ErrorCode::InvalidCouncil
+        );
+        require!(
+            threshold >= 1 && threshold as usize <= members.len(),
+            ErrorCode::InvalidCouncil
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(
+                *member != Pubkey::default() && !members[..i].contains(member),
+                ErrorCode::InvalidCouncil
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Proposal for one admin operation
+///
+/// PDA: ["admin_proposal", id (u64 LE)]. Created by propose_admin_action (the
+/// proposer approves implicitly), approved via approve_admin_action and
+/// consumed by the admin-gated instruction it names. Kept after execution
+/// as an audit trail.
+#[account]
+pub struct AdminProposal {
+    /// Sequential id (AdminCouncil.next_proposal_id at creation)
+    pub id: u64,
+
+    /// Council member who proposed it
+    pub proposer: Pubkey,
+
+    /// Operation and arguments being authorized
+    pub action: AdminAction,
+
+    /// Members who approved
+    pub approvals: Vec<Pubkey>,
+
+    /// Unix timestamp of creation
+    pub created_at: i64,
+
+    /// Unix timestamp after which it can no longer execute
+    pub expires_at: i64,
+
+    /// Whether it has been executed (proposals execute once)
+    pub executed: bool,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AdminProposal {
+    /// Space required for the account (in bytes)
+    pub const SPACE: usize = 8 + // discriminator
+        8 +  // id
+        32 + // proposer
+        AdminAction::MAX_SIZE + // action
+        4 + 32 * MAX_COUNCIL_MEMBERS + // approvals vec
+        8 +  // created_at
+        8 +  // expires_at
+        1 +  // executed
+        1;   // bump
+}
+
+/// Authorize an admin-gated instruction
+///
+/// - Single admin: `signer` must be Treasury.admin
+/// - Council mode (Treasury.admin is the AdminCouncil PDA): `signer` must be a
+///   member and `proposal` an unexpired, unexecuted proposal for exactly
+///   `action` with at least `threshold` member approvals; it is marked executed
+pub fn authorize_admin(
+    treasury: &Treasury,
+    signer: &Pubkey,
+    council: Option<&Account<AdminCouncil>>,
+    proposal: Option<&mut Account<AdminProposal>>,
+    action: &AdminAction,
+) -> Result<()> {
+    if treasury.admin == *signer {
+        return Ok(());
+    }
+
+    let council = council
+        .filter(|c| c.key() == treasury.admin)
+        .ok_or(ErrorCode::Unauthorized)?;
+    require!(council.is_member(signer), ErrorCode::NotCouncilMember);
+
+    let proposal = proposal.ok_or(ErrorCode::AdminProposalRequired)?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !proposal.executed && now <= proposal.expires_at && proposal.action == *action,
+        ErrorCode::AdminProposalInvalid
+    );
+    require!(
+        council.member_approvals(&proposal.approvals) >= council.threshold as usize,
+        ErrorCode::InsufficientApprovals
+    );
+
+    proposal.executed = true;
+
+    msg!("🏛️  Admin proposal #{} executed by {}", proposal.id, signer);
+    Ok(())
+}
+
+/// Authorize an admin instruction that needs no proposal (admin, or any member in council mode)
+pub fn authorize_admin_or_member(
+    treasury: &Treasury,
+    signer: &Pubkey,
+    council: Option<&Account<AdminCouncil>>,
+) -> Result<()> {
+    authorize_admin_key_or_member(&treasury.admin, signer, council)
+}
+
+/// Same as authorize_admin_or_member, for callers holding the raw Treasury.admin
+/// (migrate_treasury cannot deserialize an old-layout Treasury)
+pub fn authorize_admin_key_or_member(
+    admin: &Pubkey,
+    signer: &Pubkey,
+    council: Option<&Account<AdminCouncil>>,
+) -> Result<()> {
+    if admin == signer {
+        return Ok(());
+    }
+
+    let council = council
+        .filter(|c| c.key() == *admin)
+        .ok_or(ErrorCode::Unauthorized)?;
+    require!(council.is_member(signer), ErrorCode::NotCouncilMember);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn council(members: &[Pubkey], threshold: u8) -> AdminCouncil {
+        AdminCouncil {
+            members: members.to_vec(),
+            threshold,
+            next_proposal_id: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_removed_members_approvals_do_not_count() {
+        let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let council = council(&[a, c], 2);
+
+        assert_eq!(council.member_approvals(&[a, b]), 1);
+        assert_eq!(council.member_approvals(&[a, b, c]), 2);
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_threshold_and_duplicates() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        assert!(AdminCouncil::validate(&[a, b], 2).is_ok());
+        assert!(AdminCouncil::validate(&[a, b], 0).is_err());
+        assert!(AdminCouncil::validate(&[a, b], 3).is_err());
+        assert!(AdminCouncil::validate(&[a, a], 1).is_err());
+        assert!(AdminCouncil::validate(&[], 1).is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// Record of a YES holder's clawback vote (prevents double voting)
+///
+/// PDA: ["clawback_vote", market, voter]
+#[account]
+pub struct ClawbackVote {
+    /// Voter wallet
+    pub voter: Pubkey,
+
+    /// YES shares counted for this vote
+    pub weight: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ClawbackVote {
+    /// Space required for the account (in bytes)
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // voter
+        8 +  // weight
+        1;   // bump
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::constants::{BPS_DIVISOR, FOUNDER_BOND_BPS};
+
+/// Where a slashed founder bond goes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BondSlashDestination {
+    /// Transfer the bond to the treasury PDA
+    Treasury,
+    /// Add the bond to the market's clawback SOL pool (YesWins, claimed via claim_clawback)
+    YesVoters,
+}
+
+/// Refundable bond locked by the founder at create_market
+///
+/// PDA: ["founder_bond", market]. Holds `amount` lamports on top of its rent.
+/// Released to the founder (release_founder_bond) once the market resolved,
+/// launch steps are complete and FOUNDER_BOND_LOCK_PERIOD has passed; until
+/// then the admin can slash it (slash_founder_bond). Either way the account
+/// is closed, so its existence means the bond is still locked.
+#[account]
+pub struct FounderBond {
+    /// Market this bond belongs to
+    pub market: Pubkey,
+
+    /// Founder who posted the bond (receives it on release)
+    pub founder: Pubkey,
+
+    /// Bond amount (lamports, excluding rent)
+    pub amount: u64,
+
+    /// Unix timestamp when the bond was locked
+    pub locked_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl FounderBond {
+    /// Space required for the account (in bytes)
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // market
+        32 + // founder
+        8 +  // amount
+        8 +  // locked_at
+        1;   // bump
+
+    /// Bond required for a market with `target_pool`
+    pub fn required_amount(target_pool: u64) -> u64 {
+        (target_pool as u128 * FOUNDER_BOND_BPS as u128 / BPS_DIVISOR as u128) as u64
+    }
+}
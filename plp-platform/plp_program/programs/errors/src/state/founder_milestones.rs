@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use crate::constants::{BPS_DIVISOR, MAX_FOUNDER_MILESTONES};
+use crate::errors::ErrorCode;
+
+/// One milestone gating part of the founder's vested SOL
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Milestone {
+    /// Share of FounderVesting.vesting_sol gated by this milestone (basis points)
+    pub bps: u16,
+
+    /// Whether the milestone has been attested (reviewer or YES holder majority)
+    pub attested: bool,
+
+    /// Unix timestamp of attestation (0 = not attested)
+    pub attested_at: i64,
+
+    /// YES shares that voted to attest this milestone
+    pub votes_for: u64,
+}
+
+impl Milestone {
+    /// Serialized size: 2 (bps) + 1 (attested) + 8 (attested_at) + 8 (votes_for)
+    pub const SIZE: usize = 2 + 1 + 8 + 8;
+}
+
+/// Milestones gating founder excess SOL vesting
+///
+/// PDA: ["founder_milestones", market]. Set by the founder via
+/// configure_milestones before the first trade. Each milestone's slice of the
+/// vested SOL follows the normal time schedule but only once attested via
+/// attest_milestone; the rest of the vested SOL is gated by time alone.
+///
+/// Votes are measured against market.total_yes_shares, so YesWins positions
+/// cannot be closed (close_position) while any milestone is unattested.
+#[account]
+pub struct FounderMilestones {
+    /// Market these milestones belong to
+    pub market: Pubkey,
+
+    /// Admin-appointed reviewer allowed to attest directly (default = none)
+    pub reviewer: Pubkey,
+
+    /// Milestones in order (max MAX_FOUNDER_MILESTONES)
+    pub milestones: Vec<Milestone>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl FounderMilestones {
+    /// Space required for the account (in bytes)
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // market
+        32 + // reviewer
+        4 + MAX_FOUNDER_MILESTONES * Milestone::SIZE + // milestones vec
+        1;   // bump
+
+    /// Part of `vesting_sol` gated by milestone `index`
+    pub fn milestone_amount(&self, index: usize, vesting_sol: u64) -> u64 {
+        (vesting_sol as u128 * self.milestones[index].bps as u128 / BPS_DIVISOR as u128) as u64
+    }
+
+    /// Part of `vesting_sol` gated by any milestone
+    pub fn gated_amount(&self, vesting_sol: u64) -> u64 {
+        (0..self.milestones.len())
+            .map(|i| self.milestone_amount(i, vesting_sol))
+            .sum()
+    }
+
+    /// Whether every milestone has been attested
+    pub fn all_attested(&self) -> bool {
+        self.milestones.iter().all(|milestone| milestone.attested)
+    }
+
+    /// Add `weight` YES shares to milestone `index`, attesting it at `now` once
+    /// votes exceed half of `total_yes_shares`; returns whether it is attested
+    pub fn record_vote(
+        &mut self,
+        index: usize,
+        weight: u64,
+        total_yes_shares: u64,
+        now: i64,
+    ) -> Result<bool> {
+        let milestone = &mut self.milestones[index];
+        milestone.votes_for = milestone
+            .votes_for
+            .checked_add(weight)
+            .ok_or(ErrorCode::MathError)?;
+
+        // Simple majority of all YES shares
+        if milestone.votes_for as u128 * 2 > total_yes_shares as u128 {
+            milestone.attested = true;
+            milestone.attested_at = now;
+        }
+
+        Ok(milestone.attested)
+    }
+
+    /// Part of `vesting_sol` released by attested milestones
+    pub fn attested_amount(&self, vesting_sol: u64) -> u64 {
+        (0..self.milestones.len())
+            .filter(|&i| self.milestones[i].attested)
+            .map(|i| self.milestone_amount(i, vesting_sol))
+            .sum()
+    }
+}
+
+/// Record of a YES holder's milestone vote (prevents double voting)
+///
+/// PDA: ["milestone_vote", market, milestone_index, voter]
+#[account]
+pub struct MilestoneVote {
+    /// Voter wallet
+    pub voter: Pubkey,
+
+    /// YES shares counted for this vote
+    pub weight: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl MilestoneVote {
+    /// Space required for the account (in bytes)
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // voter
+        8 +  // weight
+        1;   // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn milestones(bps: &[u16], attested: &[bool]) -> FounderMilestones {
+        FounderMilestones {
+            market: Pubkey::default(),
+            reviewer: Pubkey::default(),
+            milestones: bps
+                .iter()
+                .zip(attested)
+                .map(|(&bps, &attested)| Milestone {
+                    bps,
+                    attested,
+                    attested_at: 0,
+                    votes_for: 0,
+                })
+                .collect(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_only_attested_milestones_release() {
+        let m = milestones(&[2500, 2500], &[true, false]);
+
+        assert_eq!(m.gated_amount(1_000_000), 500_000);
+        assert_eq!(m.attested_amount(1_000_000), 250_000);
+    }
+
+    #[test]
+    fn test_vote_majority_attests_and_unblocks_close() {
+        let mut m = milestones(&[5000], &[false]);
+        assert!(!m.all_attested());
+
+        // Exactly half of 1000 YES shares is not a majority
+        assert!(!m.record_vote(0, 500, 1_000, 10).unwrap());
+        assert!(!m.all_attested());
+
+        assert!(m.record_vote(0, 1, 1_000, 20).unwrap());
+        assert_eq!(m.milestones[0].votes_for, 501);
+        assert_eq!(m.milestones[0].attested_at, 20);
+        assert!(m.all_attested());
+    }
+
+    #[test]
+    fn test_all_attested_releases_all_gated() {
+        let m = milestones(&[3333, 3333], &[true, true]);
+
+        for vesting_sol in [1u64, 7, 999_999, 123_456_789] {
+            assert_eq!(m.attested_amount(vesting_sol), m.gated_amount(vesting_sol));
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+/// Optional founder registry entry
+///
+/// PDA: ["founder_profile", founder]. Created and updated by the founder via
+/// set_founder_profile; `verified` is set by the founder verifier or admin
+/// via verify_founder. create_market requires a verified profile for target
+/// pools at or above Treasury.verification_min_target, and counts the
+/// founder's markets whenever the profile is passed.
+///
+/// Handle and website are stored as sha256 hashes; the frontend keeps the
+/// plain values and checks them against these.
+#[account]
+pub struct FounderProfile {
+    /// Founder wallet
+    pub founder: Pubkey,
+
+    /// sha256 of the founder's display handle
+    pub handle_hash: [u8; 32],
+
+    /// sha256 of the founder's website URL
+    pub website_hash: [u8; 32],
+
+    /// Whether the founder is verified (cleared when handle/website change)
+    pub verified: bool,
+
+    /// Verifier or admin who last set `verified` (default = never reviewed)
+    pub verified_by: Pubkey,
+
+    /// Unix timestamp of the last verification change
+    pub verified_at: i64,
+
+    /// Markets created while passing this profile to create_market
+    pub markets_created: u32,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl FounderProfile {
+    /// Space required for the account (in bytes)
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // founder
+        32 + // handle_hash
+        32 + // website_hash
+        1 +  // verified
+        32 + // verified_by
+        8 +  // verified_at
+        4 +  // markets_created
+        1;   // bump
+}
@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use super::{FounderMilestones, VestingSchedule};
 
 /// Founder vesting schedule for excess SOL distribution
 ///
 /// Stores vesting information for founder's excess SOL (when pool > 50 SOL)
 /// - 8% immediate (claimable at resolution)
-/// - 92% vested (linear over 12 months)
+/// - 92% vested (per market.founder_vesting_schedule, default linear over 12 months),
+///   optionally partly gated by attested milestones (see FounderMilestones)
 #[account]
 pub struct FounderVesting {
     /// Market this vesting schedule belongs to
@@ -19,7 +22,7 @@ pub struct FounderVesting {
     /// Immediate SOL (8%, claimable right away)
     pub immediate_sol: u64,
 
-    /// Vesting SOL (92%, vested over 12 months)
+    /// Vesting SOL (92%, vested per market.founder_vesting_schedule)
     pub vesting_sol: u64,
 
     /// SOL already claimed by founder (includes both immediate and vested)
@@ -31,7 +34,8 @@ pub struct FounderVesting {
     /// Unix timestamp when vesting started (at market resolution)
     pub vesting_start: i64,
 
-    /// Vesting duration in seconds (12 months = 31,104,000 seconds)
+    /// Deprecated: effective duration recorded at init for display only.
+    /// Unlocks always follow market.founder_vesting_schedule; kept for account layout.
     pub vesting_duration: i64,
 
     /// Bump seed for PDA
@@ -52,38 +56,41 @@ impl FounderVesting {
         8 +  // vesting_duration
         1;   // bump
 
-    /// 12 months in seconds (12 * 30 * 24 * 60 * 60 = 31,104,000 seconds)
-    pub const VESTING_DURATION_SECONDS: i64 = 31_104_000;
-
-    /// Calculate how much vested SOL is currently unlocked (linear vesting)
+    /// Calculate how much vested SOL is currently unlocked
     ///
-    /// Formula: (vesting_sol * elapsed_time) / vesting_duration
-    /// Capped at vesting_sol after vesting period ends
+    /// Follows the market's founder vesting schedule (cliff / linear / monthly step).
+    /// With milestones, each milestone's slice only follows the schedule once attested.
     /// Note: This only calculates vested SOL, not immediate SOL
-    pub fn calculate_unlocked_vested_sol(&self, current_timestamp: i64) -> Result<u64> {
-        let elapsed = current_timestamp
-            .checked_sub(self.vesting_start)
-            .unwrap_or(0);
-
-        // If vesting period is complete, all vested SOL is unlocked
-        if elapsed >= self.vesting_duration {
-            return Ok(self.vesting_sol);
-        }
-
-        // If vesting hasn't started yet, no vested SOL unlocked
-        if elapsed <= 0 {
-            return Ok(0);
-        }
-
-        // Calculate linear unlock: (vesting_sol * elapsed) / duration
-        let unlocked = (self.vesting_sol as u128 * elapsed as u128
-            / self.vesting_duration as u128) as u64;
-
-        Ok(unlocked)
+    pub fn calculate_unlocked_vested_sol(
+        &self,
+        schedule: &VestingSchedule,
+        milestones: Option<&FounderMilestones>,
+        current_timestamp: i64,
+    ) -> Result<u64> {
+        let Some(milestones) = milestones else {
+            return Ok(schedule.unlocked_amount(self.vesting_sol, self.vesting_start, current_timestamp));
+        };
+
+        // Time-only slice + attested milestone slices, both on the schedule
+        let time_only_sol = self
+            .vesting_sol
+            .checked_sub(milestones.gated_amount(self.vesting_sol))
+            .ok_or(ErrorCode::MathError)?;
+        let released_sol = milestones.attested_amount(self.vesting_sol);
+
+        schedule
+            .unlocked_amount(time_only_sol, self.vesting_start, current_timestamp)
+            .checked_add(schedule.unlocked_amount(released_sol, self.vesting_start, current_timestamp))
+            .ok_or(ErrorCode::MathError.into())
     }
 
     /// Calculate total claimable SOL (immediate + vested - already claimed)
-    pub fn calculate_claimable_sol(&self, current_timestamp: i64) -> Result<u64> {
+    pub fn calculate_claimable_sol(
+        &self,
+        schedule: &VestingSchedule,
+        milestones: Option<&FounderMilestones>,
+        current_timestamp: i64,
+    ) -> Result<u64> {
         let mut claimable = 0u64;
 
         // Add immediate SOL if not yet claimed
@@ -94,7 +101,7 @@ impl FounderVesting {
         }
 
         // Add unlocked vested SOL
-        let unlocked_vested = self.calculate_unlocked_vested_sol(current_timestamp)?;
+        let unlocked_vested = self.calculate_unlocked_vested_sol(schedule, milestones, current_timestamp)?;
         let vested_claimed = self.claimed_sol.saturating_sub(
             if self.immediate_claimed { self.immediate_sol } else { 0 }
         );
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use super::VestingSchedule;
 
 /// Market phase for tracking prediction vs funding stages
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -33,6 +34,8 @@ pub enum MarketResolution {
 /// - The metadata URI (used during Pump.fun token launch),
 /// - Optional address of the newly created token mint (set when YES wins),
 /// - Current pool balance for tracking actual SOL held
+/// - Optional soft-close (anti-sniping) settings that extend expiry on late trades
+/// - Optional outcome token mode (YES/NO SPL mints instead of position-only shares)
 ///
 /// Notes:
 /// * Uses Constant Product AMM (x * y = k) for pricing
@@ -105,16 +108,253 @@ pub struct Market {
 
     /// PDA bump seed
     pub bump: u8,
+
+    // -------------------------
+    // Fields below were appended after v2. Existing accounts are grown with
+    // zero-filled space (migrate_market_v3), so zero must mean "disabled".
+    // -------------------------
+
+    /// Soft-close window in seconds: trades within this long of expiry_time
+    /// push expiry_time out (0 = soft close disabled)
+    pub soft_close_window: i64,
+
+    /// Seconds added to expiry_time by each trade inside the soft-close window
+    pub soft_close_extension: i64,
+
+    /// Hard maximum end time; soft-close extensions never move expiry_time past this
+    pub hard_expiry_time: i64,
+
+    /// Whether buys mint YES/NO outcome SPL tokens (opt-in, see enable_outcome_tokens)
+    /// Mints are PDAs ["yes_mint", market] / ["no_mint", market] with the market as authority
+    pub outcome_tokens_enabled: bool,
+
+    /// Net SOL invested on the YES side (outcome token markets only, backs token refunds)
+    pub yes_net_invested: u64,
+
+    /// Net SOL invested on the NO side (outcome token markets only, backs token refunds)
+    pub no_net_invested: u64,
+
+    /// Claim deadline stamped at resolution (0 = legacy: expiry_time + DEFAULT_CLAIM_PERIOD)
+    pub claim_deadline: i64,
+
+    /// YES voter tokens already claimed via claim_rewards
+    pub yes_voter_tokens_claimed: u64,
+
+    /// Whether unclaimed funds were swept to the treasury (claims closed)
+    pub unclaimed_swept: bool,
+
+    /// Unclaimed SOL swept to the treasury
+    pub swept_sol: u64,
+
+    /// Unclaimed YES voter tokens swept to the treasury
+    pub swept_tokens: u64,
+
+    /// Whether the market's token account was closed after distribution (YesWins)
+    pub token_account_closed: bool,
+
+    /// Team token allocation (33% of tokens bought at resolution, see TeamVesting)
+    pub team_tokens_allocated: u64,
+
+    /// Unlock schedule for team token vesting (zeroed = default, see configure_vesting)
+    pub team_vesting_schedule: VestingSchedule,
+
+    /// Unlock schedule for founder excess SOL vesting (zeroed = default)
+    pub founder_vesting_schedule: VestingSchedule,
+
+    /// Whether team tokens are split across beneficiaries (TeamSplit PDA, see configure_team_split)
+    pub team_split_enabled: bool,
+
+    /// Whether the platform admin may revoke unvested team/founder allocations (opt-in, see configure_vesting)
+    pub vesting_revocable: bool,
+
+    /// Time team vesting was revoked; unlocks stop accruing after this (0 = not revoked)
+    pub team_vesting_revoked_at: i64,
+
+    /// Time founder SOL vesting was revoked (0 = not revoked)
+    pub founder_vesting_revoked_at: i64,
+
+    /// YES voter tokens stream linearly over this many seconds from resolution (0 = paid at once)
+    pub voter_vesting_duration: i64,
+
+    /// Time the market was resolved (0 for markets resolved before this was recorded)
+    pub resolved_at: i64,
+
+    /// Whether part of founder vested SOL is gated by milestones (FounderMilestones PDA)
+    pub founder_milestones_enabled: bool,
+
+    /// YES shares that voted to claw back unvested founder/team allocations
+    pub clawback_votes: u64,
+
+    /// Unix timestamp of the first clawback execution (0 = not executed)
+    pub clawback_executed_at: i64,
+
+    /// Unvested team tokens clawed back for YES voters
+    pub clawback_tokens: u64,
+
+    /// Unvested founder SOL clawed back for YES voters (lamports)
+    pub clawback_sol: u64,
+
+    /// Clawed back tokens already claimed by YES voters
+    pub clawback_tokens_claimed: u64,
+
+    /// Clawed back SOL already claimed by YES voters (lamports)
+    pub clawback_sol_claimed: u64,
+
+    /// Instructions paused for this market by the admin (PAUSE_* bitmask, 0 = none)
+    pub paused: u8,
+
+    /// Creation fee paid to the treasury (lamports)
+    pub creation_fee_paid: u64,
+
+    /// Trade fees paid to the treasury by buys and side switches (lamports)
+    pub trade_fees_generated: u64,
+
+    /// Completion fee paid to the treasury at resolution (lamports)
+    pub completion_fee_paid: u64,
 }
 
 impl Market {
     /// Calculate space needed for Market account
-    /// 32 (founder) + 64 (ipfs_cid) + 8 (target_pool) + 8 (pool_balance) + 8 (distribution_pool)
+    /// 32 (founder) + 4 + 59 (ipfs_cid, length prefix + MAX_IPFS_CID_LEN) + 8 (target_pool)
+    /// + 8 (pool_balance) + 8 (distribution_pool)
     /// + 8 (yes_pool) + 8 (no_pool) + 8 (total_yes_shares) + 8 (total_no_shares)
-    /// + 8 (expiry_time) + 1 (phase enum) + 1 (resolution enum) + 200 (metadata_uri)
+    /// + 8 (expiry_time) + 1 (phase enum) + 1 (resolution enum)
+    /// + 4 + 200 (metadata_uri, length prefix + MAX_METADATA_URI_LEN)
     /// + 33 (token_mint option) + 8 (platform_tokens_allocated) + 1 (platform_tokens_claimed)
     /// + 8 (yes_voter_tokens_allocated) + 8 (founder_excess_sol_allocated) + 1 (founder_vesting_initialized)
-    /// + 32 (treasury) + 1 (bump) = ~443 bytes
-    /// Adding padding for safety: 472 bytes
-    pub const SPACE: usize = 8 + 472;
+    /// + 32 (treasury) + 1 (bump) + 8 (soft_close_window) + 8 (soft_close_extension)
+    /// + 8 (hard_expiry_time) + 1 (outcome_tokens_enabled) + 8 (yes_net_invested)
+    /// + 8 (no_net_invested) + 8 (claim_deadline) + 8 (yes_voter_tokens_claimed)
+    /// + 1 (unclaimed_swept) + 8 (swept_sol) + 8 (swept_tokens)
+    /// + 1 (token_account_closed) + 8 (team_tokens_allocated) + 17 (team_vesting_schedule)
+    /// + 17 (founder_vesting_schedule) + 1 (team_split_enabled) + 1 (vesting_revocable)
+    /// + 8 (team_vesting_revoked_at) + 8 (founder_vesting_revoked_at) + 8 (voter_vesting_duration)
+    /// + 8 (resolved_at) + 1 (founder_milestones_enabled) + 8 (clawback_votes)
+    /// + 8 (clawback_executed_at) + 8 (clawback_tokens) + 8 (clawback_sol)
+    /// + 8 (clawback_tokens_claimed) + 8 (clawback_sol_claimed) + 1 (paused)
+    /// + 8 (creation_fee_paid) + 8 (trade_fees_generated) + 8 (completion_fee_paid) = 682 bytes
+    /// Adding padding for safety: 700 bytes
+    ///
+    /// Existing accounts are grown with migrate_market_v3 after any increase.
+    pub const SPACE: usize = 8 + 700;
+
+    /// Deadline after which unclaimed funds can be swept and the market closed
+    pub fn effective_claim_deadline(&self) -> i64 {
+        if self.claim_deadline > 0 {
+            self.claim_deadline
+        } else {
+            self.expiry_time.saturating_add(crate::constants::DEFAULT_CLAIM_PERIOD)
+        }
+    }
+
+    /// Team token allocation recorded at resolution
+    ///
+    /// Markets resolved before team_tokens_allocated existed derive it from the
+    /// platform allocation (2% → 33%), rounding down so it never exceeds the real slice.
+    pub fn effective_team_tokens_allocated(&self) -> u64 {
+        use crate::constants::{PLATFORM_TOKEN_SHARE_BPS, TEAM_TOKEN_SHARE_BPS};
+
+        if self.team_tokens_allocated > 0 {
+            self.team_tokens_allocated
+        } else {
+            (self.platform_tokens_allocated as u128 * TEAM_TOKEN_SHARE_BPS as u128
+                / PLATFORM_TOKEN_SHARE_BPS as u128) as u64
+        }
+    }
+
+    /// Linear schedule for YES voter tokens, if voter vesting is enabled
+    pub fn voter_vesting_schedule(&self) -> Option<VestingSchedule> {
+        if self.voter_vesting_duration > 0 {
+            Some(VestingSchedule {
+                curve: super::VestingCurve::Linear,
+                cliff_seconds: 0,
+                duration_seconds: self.voter_vesting_duration,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Time used for team vesting unlocks (frozen at revocation)
+    pub fn team_vesting_time(&self, now: i64) -> i64 {
+        if self.team_vesting_revoked_at > 0 {
+            now.min(self.team_vesting_revoked_at)
+        } else {
+            now
+        }
+    }
+
+    /// Time used for founder SOL vesting unlocks (frozen at revocation)
+    pub fn founder_vesting_time(&self, now: i64) -> i64 {
+        if self.founder_vesting_revoked_at > 0 {
+            now.min(self.founder_vesting_revoked_at)
+        } else {
+            now
+        }
+    }
+
+    /// Whether any of the PAUSE_* `flags` is paused for this market
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.paused & flags != 0
+    }
+
+    /// Whether YES votes exceed half of all YES shares
+    pub fn clawback_approved(&self) -> bool {
+        self.total_yes_shares > 0
+            && self.clawback_votes as u128 * 2 > self.total_yes_shares as u128
+    }
+
+    /// Clawed back (tokens, SOL) owed to a holder of `yes_shares` in total
+    ///
+    /// Same proportional math as YES voter token claims; the pool can grow
+    /// if execute_clawback runs again, so callers subtract what was already paid.
+    pub fn clawback_share(&self, yes_shares: u64) -> (u64, u64) {
+        if self.total_yes_shares == 0 {
+            return (0, 0);
+        }
+        let tokens = (yes_shares as u128 * self.clawback_tokens as u128
+            / self.total_yes_shares as u128) as u64;
+        let sol = (yes_shares as u128 * self.clawback_sol as u128
+            / self.total_yes_shares as u128) as u64;
+        (tokens, sol)
+    }
+
+    /// Clawed back (tokens, SOL) not yet claimed by YES holders
+    ///
+    /// Zero once sweep_unclaimed has moved the remainder to the treasury.
+    pub fn clawback_unclaimed(&self) -> (u64, u64) {
+        if self.unclaimed_swept {
+            return (0, 0);
+        }
+        (
+            self.clawback_tokens.saturating_sub(self.clawback_tokens_claimed),
+            self.clawback_sol.saturating_sub(self.clawback_sol_claimed),
+        )
+    }
+
+    /// Whether the anti-sniping soft-close rule is configured for this market
+    pub fn soft_close_enabled(&self) -> bool {
+        self.soft_close_window > 0 && self.soft_close_extension > 0
+    }
+
+    /// Expiry time after a trade at `now`, applying the soft-close rule
+    ///
+    /// A trade inside the final `soft_close_window` seconds pushes expiry_time
+    /// out by `soft_close_extension`, capped at `hard_expiry_time`.
+    /// Returns the unchanged expiry_time when soft close is disabled.
+    pub fn soft_close_expiry(&self, now: i64) -> i64 {
+        if !self.soft_close_enabled() {
+            return self.expiry_time;
+        }
+
+        let window_start = self.expiry_time.saturating_sub(self.soft_close_window);
+        if now < window_start {
+            return self.expiry_time;
+        }
+
+        self.expiry_time
+            .saturating_add(self.soft_close_extension)
+            .min(self.hard_expiry_time)
+            .max(self.expiry_time)
+    }
 }
@@ -4,9 +4,29 @@ pub mod position;
 pub mod treasury;
 pub mod team_vesting;
 pub mod founder_vesting;
+pub mod vesting_schedule;
+pub mod team_split;
+pub mod founder_milestones;
+pub mod clawback_vote;
+pub mod pending_withdrawal;
+pub mod admin_council;
+pub mod staking_pool;
+pub mod founder_profile;
+pub mod founder_bond;
+pub mod reputation;
 
 pub use market::*;
 pub use position::*;
 pub use treasury::*;
 pub use team_vesting::*;
 pub use founder_vesting::*;
+pub use vesting_schedule::*;
+pub use team_split::*;
+pub use founder_milestones::*;
+pub use clawback_vote::*;
+pub use pending_withdrawal::*;
+pub use admin_council::*;
+pub use staking_pool::*;
+pub use founder_profile::*;
+pub use founder_bond::*;
+pub use reputation::*;
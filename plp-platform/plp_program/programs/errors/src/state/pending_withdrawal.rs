@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+/// Treasury withdrawal waiting out the timelock
+///
+/// PDA: ["withdrawal", id (u64 LE)]. Created by queue_withdrawal, closed by
+/// execute_withdrawal or cancel_withdrawal (rent back to the admin).
+#[account]
+pub struct PendingWithdrawal {
+    /// Sequential id (Treasury.next_withdrawal_id at queue time)
+    pub id: u64,
+
+    /// Allowlisted recipient of the SOL
+    pub recipient: Pubkey,
+
+    /// Amount to withdraw (lamports)
+    pub amount: u64,
+
+    /// Unix timestamp when queued
+    pub queued_at: i64,
+
+    /// Unix timestamp after which it can be executed
+    pub executable_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PendingWithdrawal {
+    /// Space required for the account (in bytes)
+    pub const SPACE: usize = 8 + // discriminator
+        8 +  // id
+        32 + // recipient
+        8 +  // amount
+        8 +  // queued_at
+        8 +  // executable_at
+        1;   // bump
+}
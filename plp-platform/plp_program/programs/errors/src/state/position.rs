@@ -13,6 +13,10 @@ use anchor_lang::prelude::*;
 /// The "one position per wallet" rule is enforced:
 /// - If user has YES shares, they cannot buy NO shares
 /// - If user has NO shares, they cannot buy YES shares
+/// - Users change sides via `switch_side`, which sells one side and buys the other
+///
+/// In outcome token markets, shares are also minted as YES/NO SPL tokens and
+/// claims are paid on those tokens; the position remains an accounting index.
 #[account]
 pub struct Position {
     /// The wallet that owns this position
@@ -35,12 +39,23 @@ pub struct Position {
 
     /// PDA bump seed
     pub bump: u8,
+
+    /// YES voter tokens claimed so far (voter vesting markets, claims are partial)
+    /// Lives in the original padding, so existing positions read it as zero
+    pub voter_tokens_claimed: u64,
+
+    /// Clawed back team tokens claimed so far (see claim_clawback)
+    pub clawback_tokens_claimed: u64,
+
+    /// Clawed back founder SOL claimed so far (lamports, see claim_clawback)
+    pub clawback_sol_claimed: u64,
 }
 
 impl Position {
     /// Calculate space needed for Position account
     /// 32 (user) + 32 (market) + 8 (yes_shares) + 8 (no_shares)
-    /// + 8 (total_invested) + 1 (claimed) + 1 (bump) = 90 bytes
+    /// + 8 (total_invested) + 1 (claimed) + 1 (bump) + 8 (voter_tokens_claimed)
+    /// + 8 (clawback_tokens_claimed) + 8 (clawback_sol_claimed) = 114 bytes
     /// Adding padding for safety: 128 bytes
     pub const SPACE: usize = 8 + 128;
 }
@@ -0,0 +1,251 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use super::MarketResolution;
+
+/// How a position settled through claim_rewards
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SettlementOutcome {
+    /// NoWins payout in SOL
+    Win,
+    /// Position on the losing side (nothing paid, position closed)
+    Loss,
+    /// Refund of net invested SOL
+    Refund,
+}
+
+/// Per-wallet predictor track record
+///
+/// PDA: ["reputation", wallet]. Created by the first instruction that settles
+/// one of the wallet's positions and updated once per settled position, by
+/// whichever instruction settles it: claim_rewards (user), crank_claims
+/// (anyone, incl. losing positions the user never settles) or close_position
+/// (Refund positions closed without claiming count as a refund of 0):
+/// - NoWins wins, losses and refunds are SOL-settled and feed `realized_pnl`
+///   (sol_returned - sol_invested)
+/// - YesWins wins pay project tokens, so their invested SOL is kept in
+///   `token_cost_basis` and the tokens in `tokens_received` (voter vesting
+///   claims add tokens on every call but count the win once)
+///
+/// The record follows the position: transfer_position before resolution moves
+/// its eventual outcome (and credit) to the recipient wallet. Outcome token
+/// claims are not recorded (the tokens may have changed hands).
+#[account]
+pub struct Reputation {
+    /// Wallet this record belongs to
+    pub wallet: Pubkey,
+
+    /// Positions settled (wins + losses + refunds)
+    pub markets_participated: u32,
+
+    /// Positions on the winning side
+    pub wins: u32,
+
+    /// Positions on the losing side
+    pub losses: u32,
+
+    /// Positions refunded
+    pub refunds: u32,
+
+    /// SOL invested in SOL-settled positions (lamports)
+    pub sol_invested: u64,
+
+    /// SOL paid back on SOL-settled positions (lamports)
+    pub sol_returned: u64,
+
+    /// sol_returned - sol_invested (lamports)
+    pub realized_pnl: i64,
+
+    /// SOL invested in YesWins positions paid in project tokens (lamports)
+    pub token_cost_basis: u64,
+
+    /// Project tokens received from YesWins claims (raw units, mixed mints)
+    pub tokens_received: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Reputation {
+    /// Empty record for `wallet`
+    pub fn new(wallet: Pubkey, bump: u8) -> Self {
+        Self {
+            wallet,
+            markets_participated: 0,
+            wins: 0,
+            losses: 0,
+            refunds: 0,
+            sol_invested: 0,
+            sol_returned: 0,
+            realized_pnl: 0,
+            token_cost_basis: 0,
+            tokens_received: 0,
+            bump,
+        }
+    }
+
+    /// Space required for the account (in bytes)
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // wallet
+        4 +  // markets_participated
+        4 +  // wins
+        4 +  // losses
+        4 +  // refunds
+        8 +  // sol_invested
+        8 +  // sol_returned
+        8 +  // realized_pnl
+        8 +  // token_cost_basis
+        8 +  // tokens_received
+        1;   // bump
+
+    /// Record a position settled in SOL (or a loss, with `returned` = 0)
+    pub fn record_settlement(
+        &mut self,
+        outcome: SettlementOutcome,
+        invested: u64,
+        returned: u64,
+    ) -> Result<()> {
+        self.count_position(outcome)?;
+
+        self.sol_invested = self
+            .sol_invested
+            .checked_add(invested)
+            .ok_or(ErrorCode::MathError)?;
+        self.sol_returned = self
+            .sol_returned
+            .checked_add(returned)
+            .ok_or(ErrorCode::MathError)?;
+
+        let pnl = returned as i128 - invested as i128;
+        self.realized_pnl = i64::try_from(self.realized_pnl as i128 + pnl)
+            .map_err(|_| ErrorCode::MathError)?;
+
+        Ok(())
+    }
+
+    /// Record a YesWins token claim; `first_claim` counts the win and cost basis
+    pub fn record_token_claim(&mut self, invested: u64, tokens: u64, first_claim: bool) -> Result<()> {
+        if first_claim {
+            self.count_position(SettlementOutcome::Win)?;
+            self.token_cost_basis = self
+                .token_cost_basis
+                .checked_add(invested)
+                .ok_or(ErrorCode::MathError)?;
+        }
+
+        self.tokens_received = self
+            .tokens_received
+            .checked_add(tokens)
+            .ok_or(ErrorCode::MathError)?;
+
+        Ok(())
+    }
+
+    fn count_position(&mut self, outcome: SettlementOutcome) -> Result<()> {
+        let counter = match outcome {
+            SettlementOutcome::Win => &mut self.wins,
+            SettlementOutcome::Loss => &mut self.losses,
+            SettlementOutcome::Refund => &mut self.refunds,
+        };
+        *counter = counter.checked_add(1).ok_or(ErrorCode::MathError)?;
+
+        self.markets_participated = self
+            .markets_participated
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+
+        Ok(())
+    }
+}
+
+/// Per-founder launch track record
+///
+/// PDA: ["founder_reputation", founder]. Created by the first resolve_market
+/// of one of the founder's markets (resolver pays rent) and updated on every
+/// resolution. Markets forced into Refund by emergency_refund are not counted.
+#[account]
+pub struct FounderReputation {
+    /// Founder wallet
+    pub founder: Pubkey,
+
+    /// Markets resolved (any outcome)
+    pub markets_launched: u32,
+
+    /// Markets resolved YesWins (token launched)
+    pub yes_wins: u32,
+
+    /// Markets resolved NoWins
+    pub no_wins: u32,
+
+    /// Markets resolved Refund
+    pub refunds: u32,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl FounderReputation {
+    /// Space required for the account (in bytes)
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // founder
+        4 +  // markets_launched
+        4 +  // yes_wins
+        4 +  // no_wins
+        4 +  // refunds
+        1;   // bump
+
+    /// Count a market resolution
+    pub fn record_resolution(&mut self, resolution: MarketResolution) -> Result<()> {
+        let counter = match resolution {
+            MarketResolution::YesWins => &mut self.yes_wins,
+            MarketResolution::NoWins => &mut self.no_wins,
+            MarketResolution::Refund => &mut self.refunds,
+            MarketResolution::Unresolved => return Err(ErrorCode::InvalidResolutionState.into()),
+        };
+        *counter = counter.checked_add(1).ok_or(ErrorCode::MathError)?;
+
+        self.markets_launched = self
+            .markets_launched
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reputation() -> Reputation {
+        Reputation::new(Pubkey::default(), 0)
+    }
+
+    #[test]
+    fn test_settlements_track_pnl() {
+        let mut rep = reputation();
+
+        rep.record_settlement(SettlementOutcome::Win, 1_000, 1_800).unwrap();
+        rep.record_settlement(SettlementOutcome::Loss, 500, 0).unwrap();
+        rep.record_settlement(SettlementOutcome::Refund, 1_000, 985).unwrap();
+
+        assert_eq!(rep.markets_participated, 3);
+        assert_eq!((rep.wins, rep.losses, rep.refunds), (1, 1, 1));
+        assert_eq!(rep.sol_invested, 2_500);
+        assert_eq!(rep.sol_returned, 2_785);
+        assert_eq!(rep.realized_pnl, 285);
+    }
+
+    #[test]
+    fn test_vested_token_claims_count_one_win() {
+        let mut rep = reputation();
+
+        rep.record_token_claim(2_000, 100, true).unwrap();
+        rep.record_token_claim(2_000, 50, false).unwrap();
+
+        assert_eq!(rep.markets_participated, 1);
+        assert_eq!(rep.wins, 1);
+        assert_eq!(rep.token_cost_basis, 2_000);
+        assert_eq!(rep.tokens_received, 150);
+        assert_eq!(rep.realized_pnl, 0);
+    }
+}
@@ -0,0 +1,293 @@
+use anchor_lang::prelude::*;
+use crate::constants::{BPS_DIVISOR, STAKING_REWARD_PRECISION};
+use crate::errors::ErrorCode;
+
+/// Platform token staking pool
+///
+/// PDA: ["staking_pool"]. Holders of `stake_mint` lock tokens in the stake vault
+/// (token account PDA ["stake_vault"], authority = this PDA) and earn
+/// `staker_share_bps` of every fee the treasury collects.
+///
+/// distribute_staking_rewards moves the stakers' share of fees collected since
+/// `fees_checkpoint` (Treasury.total_fees) from the treasury into this account
+/// and raises `reward_per_share`; each StakePosition earns
+/// amount * reward_per_share / 1e12 minus its reward_debt (MasterChef-style).
+#[account]
+pub struct StakingPool {
+    /// Platform token that can be staked
+    pub stake_mint: Pubkey,
+
+    /// Token account holding staked tokens (PDA ["stake_vault"])
+    pub stake_vault: Pubkey,
+
+    /// Share of treasury fee inflows paid to stakers (basis points)
+    pub staker_share_bps: u16,
+
+    /// Tokens currently staked
+    pub total_staked: u64,
+
+    /// Accumulated SOL reward per staked token, scaled by STAKING_REWARD_PRECISION
+    pub reward_per_share: u128,
+
+    /// Treasury.total_fees already distributed (or skipped while nothing was staked)
+    pub fees_checkpoint: u64,
+
+    /// SOL moved from the treasury to stakers over the pool's lifetime (lamports)
+    pub total_rewards: u64,
+
+    /// SOL claimed by stakers over the pool's lifetime (lamports)
+    pub total_claimed: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Bump seed for the stake vault PDA
+    pub vault_bump: u8,
+}
+
+impl StakingPool {
+    /// Space required for the account (in bytes)
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // stake_mint
+        32 + // stake_vault
+        2 +  // staker_share_bps
+        8 +  // total_staked
+        16 + // reward_per_share
+        8 +  // fees_checkpoint
+        8 +  // total_rewards
+        8 +  // total_claimed
+        1 +  // bump
+        1;   // vault_bump
+
+    /// Stakers' part of `new_fees`
+    pub fn staker_share(&self, new_fees: u64) -> u64 {
+        (new_fees as u128 * self.staker_share_bps as u128 / BPS_DIVISOR as u128) as u64
+    }
+
+    /// Reward owed to stakers for fees not yet distributed (`total_fees` = Treasury.total_fees)
+    ///
+    /// Fees collected while nothing is staked are skipped by the next distribution.
+    pub fn undistributed_reward(&self, total_fees: u64) -> u64 {
+        if self.total_staked == 0 {
+            return 0;
+        }
+        self.staker_share(total_fees.saturating_sub(self.fees_checkpoint))
+    }
+
+    /// Advance fees_checkpoint to `total_fees`, minus fees whose reward was not paid
+    ///
+    /// `new_fees` produced an `owed` reward of which only `paid` left the treasury;
+    /// the checkpoint only covers the paid fraction, so the rest is owed again
+    /// on the next distribution instead of being dropped.
+    pub fn advance_checkpoint(&mut self, total_fees: u64, new_fees: u64, owed: u64, paid: u64) {
+        if paid >= owed {
+            self.fees_checkpoint = total_fees;
+        } else {
+            let covered = (new_fees as u128 * paid as u128 / owed as u128) as u64;
+            self.fees_checkpoint = self.fees_checkpoint.saturating_add(covered);
+        }
+    }
+
+    /// Spread `reward` lamports over everything currently staked
+    pub fn accrue(&mut self, reward: u64) -> Result<()> {
+        if reward == 0 || self.total_staked == 0 {
+            return Ok(());
+        }
+
+        let increment = (reward as u128)
+            .checked_mul(STAKING_REWARD_PRECISION)
+            .ok_or(ErrorCode::MathError)?
+            / self.total_staked as u128;
+
+        self.reward_per_share = self
+            .reward_per_share
+            .checked_add(increment)
+            .ok_or(ErrorCode::MathError)?;
+        self.total_rewards = self
+            .total_rewards
+            .checked_add(reward)
+            .ok_or(ErrorCode::MathError)?;
+        Ok(())
+    }
+}
+
+/// A wallet's stake in the staking pool
+///
+/// PDA: ["stake", owner]. Created on the first stake; rewards are settled into
+/// `unclaimed` before every stake/unstake/claim so a changing amount never
+/// earns rewards distributed before it was staked.
+///
+/// Every stake locks the whole position for STAKE_LOCK_PERIOD, so tokens
+/// staked just before a distribution cannot be pulled out right after it.
+#[account]
+pub struct StakePosition {
+    /// Wallet that staked (receives rewards and unstaked tokens)
+    pub owner: Pubkey,
+
+    /// Tokens staked
+    pub amount: u64,
+
+    /// amount * reward_per_share already accounted for at the last settlement
+    pub reward_debt: u128,
+
+    /// Settled rewards not yet claimed (lamports)
+    pub unclaimed: u64,
+
+    /// Rewards claimed so far (lamports)
+    pub total_claimed: u64,
+
+    /// Unix timestamp before which unstake is rejected (last stake + STAKE_LOCK_PERIOD)
+    pub locked_until: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl StakePosition {
+    /// Space required for the account (in bytes)
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // owner
+        8 +  // amount
+        16 + // reward_debt
+        8 +  // unclaimed
+        8 +  // total_claimed
+        8 +  // locked_until
+        1;   // bump
+
+    /// amount * reward_per_share (scaled), the position's accrued rewards to date
+    fn accrued(&self, reward_per_share: u128) -> Result<u128> {
+        (self.amount as u128)
+            .checked_mul(reward_per_share)
+            .ok_or(ErrorCode::MathError.into())
+    }
+
+    /// Move rewards earned since the last settlement into `unclaimed`
+    ///
+    /// Call before changing `amount`, then `reset_debt` afterwards.
+    pub fn settle(&mut self, reward_per_share: u128) -> Result<()> {
+        let earned = (self.accrued(reward_per_share)?.saturating_sub(self.reward_debt)
+            / STAKING_REWARD_PRECISION) as u64;
+
+        self.unclaimed = self
+            .unclaimed
+            .checked_add(earned)
+            .ok_or(ErrorCode::MathError)?;
+        self.reset_debt(reward_per_share)
+    }
+
+    /// Mark everything accrued at `reward_per_share` as accounted for
+    pub fn reset_debt(&mut self, reward_per_share: u128) -> Result<()> {
+        self.reward_debt = self.accrued(reward_per_share)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(total_staked: u64) -> StakingPool {
+        StakingPool {
+            stake_mint: Pubkey::default(),
+            stake_vault: Pubkey::default(),
+            staker_share_bps: 5000,
+            total_staked,
+            reward_per_share: 0,
+            fees_checkpoint: 0,
+            total_rewards: 0,
+            total_claimed: 0,
+            bump: 0,
+            vault_bump: 0,
+        }
+    }
+
+    fn position(amount: u64, pool: &StakingPool) -> StakePosition {
+        let mut position = StakePosition {
+            owner: Pubkey::new_unique(),
+            amount,
+            reward_debt: 0,
+            unclaimed: 0,
+            total_claimed: 0,
+            locked_until: 0,
+            bump: 0,
+        };
+        position.reset_debt(pool.reward_per_share).unwrap();
+        position
+    }
+
+    #[test]
+    fn test_rewards_split_pro_rata() {
+        let mut pool = pool(400);
+        let mut a = position(100, &pool);
+        let mut b = position(300, &pool);
+
+        pool.accrue(1_000_000).unwrap();
+        a.settle(pool.reward_per_share).unwrap();
+        b.settle(pool.reward_per_share).unwrap();
+
+        assert_eq!(a.unclaimed, 250_000);
+        assert_eq!(b.unclaimed, 750_000);
+    }
+
+    #[test]
+    fn test_late_staker_earns_only_later_rewards() {
+        let mut pool = pool(100);
+        let mut early = position(100, &pool);
+
+        pool.accrue(1_000).unwrap();
+
+        // Late staker joins after the first distribution
+        pool.total_staked += 100;
+        let mut late = position(100, &pool);
+
+        pool.accrue(1_000).unwrap();
+        early.settle(pool.reward_per_share).unwrap();
+        late.settle(pool.reward_per_share).unwrap();
+
+        assert_eq!(early.unclaimed, 1_500);
+        assert_eq!(late.unclaimed, 500);
+    }
+
+    #[test]
+    fn test_capped_distribution_keeps_remainder_owed() {
+        let mut pool = pool(100);
+
+        // 1000 lamports of new fees owe 500, only 200 could be paid
+        pool.advance_checkpoint(1_000, 1_000, 500, 200);
+        assert_eq!(pool.fees_checkpoint, 400);
+        assert_eq!(pool.staker_share(1_000 - pool.fees_checkpoint), 300);
+
+        // Fully paid: checkpoint catches up with total_fees
+        pool.advance_checkpoint(1_000, 600, 300, 300);
+        assert_eq!(pool.fees_checkpoint, 1_000);
+    }
+
+    #[test]
+    fn test_undistributed_reward_follows_checkpoint() {
+        let mut pool = pool(100);
+        pool.fees_checkpoint = 400;
+        assert_eq!(pool.undistributed_reward(1_000), 300);
+
+        // Nothing staked: the next distribution skips these fees
+        pool.total_staked = 0;
+        assert_eq!(pool.undistributed_reward(1_000), 0);
+    }
+
+    #[test]
+    fn test_payouts_never_exceed_rewards() {
+        let mut pool = pool(3);
+        let mut positions: Vec<_> = (0..3).map(|_| position(1, &pool)).collect();
+
+        pool.accrue(100).unwrap();
+        let paid: u64 = positions
+            .iter_mut()
+            .map(|p| {
+                p.settle(pool.reward_per_share).unwrap();
+                p.unclaimed
+            })
+            .sum();
+
+        assert!(paid <= 100);
+        assert_eq!(pool.staker_share(1_000), 500);
+    }
+}
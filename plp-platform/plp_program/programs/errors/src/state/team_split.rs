@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::constants::{BPS_DIVISOR, MAX_TEAM_BENEFICIARIES};
+
+/// One recipient of the team token allocation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TeamBeneficiary {
+    /// Wallet allowed to claim this share via claim_team_tokens
+    pub wallet: Pubkey,
+
+    /// Share of the team allocation in basis points (all entries sum to 10000)
+    pub bps: u16,
+
+    /// Tokens already claimed by this beneficiary
+    pub claimed_tokens: u64,
+}
+
+impl TeamBeneficiary {
+    /// Serialized size: 32 (wallet) + 2 (bps) + 8 (claimed_tokens)
+    pub const SIZE: usize = 32 + 2 + 8;
+}
+
+/// Split of the team token allocation across several beneficiaries
+///
+/// PDA: ["team_split", market]. Set by the founder via configure_team_split
+/// before the first trade; when market.team_split_enabled is set, every
+/// beneficiary claims their own share through claim_team_tokens using the
+/// TeamVesting schedule, instead of the whole allocation going to team_wallet.
+#[account]
+pub struct TeamSplit {
+    /// Market this split belongs to
+    pub market: Pubkey,
+
+    /// Beneficiaries in claim order (max MAX_TEAM_BENEFICIARIES)
+    pub beneficiaries: Vec<TeamBeneficiary>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl TeamSplit {
+    /// Space required for the account (in bytes)
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // market
+        4 + MAX_TEAM_BENEFICIARIES * TeamBeneficiary::SIZE + // beneficiaries vec
+        1;   // bump
+
+    /// Index of `wallet` in the beneficiary list
+    pub fn find(&self, wallet: &Pubkey) -> Option<usize> {
+        self.beneficiaries.iter().position(|b| b.wallet == *wallet)
+    }
+
+    /// Beneficiary `index`'s part of `amount`
+    ///
+    /// Uses cumulative bps so the parts of all beneficiaries always add up to
+    /// exactly `amount` (rounding remainders never get stranded):
+    /// share_i = amount * cum_bps(0..=i) / 10000 - amount * cum_bps(0..i) / 10000
+    pub fn share_of(&self, index: usize, amount: u64) -> u64 {
+        let bps_before: u64 = self.beneficiaries[..index]
+            .iter()
+            .map(|b| b.bps as u64)
+            .sum();
+        let bps_through = bps_before + self.beneficiaries[index].bps as u64;
+
+        let through = amount as u128 * bps_through as u128 / BPS_DIVISOR as u128;
+        let before = amount as u128 * bps_before as u128 / BPS_DIVISOR as u128;
+
+        (through - before) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(bps: &[u16]) -> TeamSplit {
+        TeamSplit {
+            market: Pubkey::default(),
+            beneficiaries: bps
+                .iter()
+                .map(|&bps| TeamBeneficiary {
+                    wallet: Pubkey::new_unique(),
+                    bps,
+                    claimed_tokens: 0,
+                })
+                .collect(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_shares_add_up_exactly() {
+        let split = split(&[3333, 3333, 3334]);
+
+        for amount in [0u64, 1, 2, 7, 999, 1_000_001, 123_456_789_012] {
+            let total: u64 = (0..3).map(|i| split.share_of(i, amount)).sum();
+            assert_eq!(total, amount);
+        }
+    }
+
+    #[test]
+    fn test_shares_follow_bps() {
+        let split = split(&[7000, 2000, 1000]);
+
+        assert_eq!(split.share_of(0, 1_000_000), 700_000);
+        assert_eq!(split.share_of(1, 1_000_000), 200_000);
+        assert_eq!(split.share_of(2, 1_000_000), 100_000);
+    }
+}
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use super::{Market, VestingSchedule};
 
 /// Team vesting schedule for token distribution
 ///
 /// Stores vesting information for the team's 33% token allocation
 /// - 8% immediate (claimable at resolution)
-/// - 25% vested (linear over 12 months with monthly unlock)
+/// - 25% vested (per market.team_vesting_schedule, default linear over 12 months)
 #[account]
 pub struct TeamVesting {
     /// Market this vesting schedule belongs to
@@ -22,7 +24,7 @@ pub struct TeamVesting {
     /// Immediate tokens (8% of total supply, claimable right away)
     pub immediate_tokens: u64,
 
-    /// Vesting tokens (25% of total supply, vested over 12 months)
+    /// Vesting tokens (25% of total supply, vested per market.team_vesting_schedule)
     pub vesting_tokens: u64,
 
     /// Tokens already claimed by team (includes both immediate and vested)
@@ -34,7 +36,8 @@ pub struct TeamVesting {
     /// Unix timestamp when vesting started (at market resolution)
     pub vesting_start: i64,
 
-    /// Vesting duration in seconds (12 months = 31,536,000 seconds)
+    /// Deprecated: effective duration recorded at init for display only.
+    /// Unlocks always follow market.team_vesting_schedule; kept for account layout.
     pub vesting_duration: i64,
 
     /// Bump seed for PDA
@@ -56,39 +59,75 @@ impl TeamVesting {
         8 +  // vesting_duration
         1;   // bump
 
-    /// 12 months in seconds (365 days / 12 = 30.4167 days per month, but we use 30 days)
-    /// 12 * 30 * 24 * 60 * 60 = 31,104,000 seconds
-    pub const VESTING_DURATION_SECONDS: i64 = 31_104_000;
+    /// Set up the schedule from the team allocation recorded on the market
+    ///
+    /// Splits `team_tokens` into immediate (8/33) and vested (25/33) portions;
+    /// the vested portion takes any rounding remainder so the parts always sum to the total.
+    pub fn initialize(
+        &mut self,
+        market: &Market,
+        market_key: Pubkey,
+        team_tokens: u64,
+        vesting_start: i64,
+        bump: u8,
+    ) -> Result<()> {
+        use crate::constants::{TEAM_IMMEDIATE_SHARE_BPS, TEAM_TOKEN_SHARE_BPS};
+
+        let immediate_tokens = (team_tokens as u128 * TEAM_IMMEDIATE_SHARE_BPS as u128
+            / TEAM_TOKEN_SHARE_BPS as u128) as u64;
+        let vesting_tokens = team_tokens
+            .checked_sub(immediate_tokens)
+            .ok_or(ErrorCode::MathError)?;
+
+        require!(immediate_tokens > 0, ErrorCode::InsufficientBalance);
+        require!(vesting_tokens > 0, ErrorCode::InsufficientBalance);
+
+        self.market = market_key;
+        self.team_wallet = market.founder;
+        self.token_mint = market.token_mint.ok_or(ErrorCode::InvalidResolutionState)?;
+        self.total_tokens = team_tokens;
+        self.immediate_tokens = immediate_tokens;
+        self.vesting_tokens = vesting_tokens;
+        self.claimed_tokens = 0;
+        self.immediate_claimed = false;
+        self.vesting_start = vesting_start;
+        self.vesting_duration = market.team_vesting_schedule.effective().duration_seconds;
+        self.bump = bump;
+
+        Ok(())
+    }
 
-    /// Calculate how many vested tokens are currently unlocked (linear vesting)
+    /// Calculate how many vested tokens are currently unlocked
     ///
-    /// Formula: (vesting_tokens * elapsed_time) / vesting_duration
-    /// Capped at vesting_tokens after vesting period ends
+    /// Follows the market's team vesting schedule (cliff / linear / monthly step)
     /// Note: This only calculates vested tokens, not immediate tokens
-    pub fn calculate_unlocked_vested_tokens(&self, current_timestamp: i64) -> Result<u64> {
-        let elapsed = current_timestamp
-            .checked_sub(self.vesting_start)
-            .unwrap_or(0);
-
-        // If vesting period is complete, all vested tokens are unlocked
-        if elapsed >= self.vesting_duration {
-            return Ok(self.vesting_tokens);
-        }
-
-        // If vesting hasn't started yet, no vested tokens unlocked
-        if elapsed <= 0 {
-            return Ok(0);
-        }
-
-        // Calculate linear unlock: (vesting_tokens * elapsed) / duration
-        let unlocked = (self.vesting_tokens as u128 * elapsed as u128
-            / self.vesting_duration as u128) as u64;
+    pub fn calculate_unlocked_vested_tokens(
+        &self,
+        schedule: &VestingSchedule,
+        current_timestamp: i64,
+    ) -> Result<u64> {
+        Ok(schedule.unlocked_amount(self.vesting_tokens, self.vesting_start, current_timestamp))
+    }
 
-        Ok(unlocked)
+    /// Calculate all tokens unlocked so far (immediate + unlocked vested), ignoring claims
+    pub fn calculate_unlocked_total_tokens(
+        &self,
+        schedule: &VestingSchedule,
+        current_timestamp: i64,
+    ) -> Result<u64> {
+        let unlocked_vested = self.calculate_unlocked_vested_tokens(schedule, current_timestamp)?;
+
+        self.immediate_tokens
+            .checked_add(unlocked_vested)
+            .ok_or(ErrorCode::MathError.into())
     }
 
     /// Calculate total claimable tokens (immediate + vested - already claimed)
-    pub fn calculate_claimable_tokens(&self, current_timestamp: i64) -> Result<u64> {
+    pub fn calculate_claimable_tokens(
+        &self,
+        schedule: &VestingSchedule,
+        current_timestamp: i64,
+    ) -> Result<u64> {
         let mut claimable = 0u64;
 
         // Add immediate tokens if not yet claimed
@@ -99,7 +138,7 @@ impl TeamVesting {
         }
 
         // Add unlocked vested tokens
-        let unlocked_vested = self.calculate_unlocked_vested_tokens(current_timestamp)?;
+        let unlocked_vested = self.calculate_unlocked_vested_tokens(schedule, current_timestamp)?;
         let vested_claimed = self.claimed_tokens.saturating_sub(
             if self.immediate_claimed { self.immediate_tokens } else { 0 }
         );
@@ -1,14 +1,128 @@
 use anchor_lang::prelude::*;
+use crate::constants::{DEFAULT_WITHDRAWAL_DELAY, MAX_WITHDRAWAL_RECIPIENTS, PNL_WALLET};
+use crate::errors::ErrorCode;
+use std::str::FromStr;
 
 /// Platform Treasury PDA
 /// Holds accumulated platform fees from all markets.
+///
+/// Fields after `bump` were appended later; existing treasuries are grown with
+/// zero-filled space (migrate_treasury), so zero must mean "use the default".
+/// Every field is fixed-size so appended fields always start at the same offset.
 #[account]
 pub struct Treasury {
-    pub admin: Pubkey,   // Founder or platform wallet
-    pub total_fees: u64, // Total fees collected
-    pub bump: u8,        // PDA bump
+    pub admin: Pubkey,     // Founder or platform wallet
+    pub total_fees: u64,   // Total fees collected (lifetime, not reduced by withdrawals)
+    pub bump: u8,          // PDA bump
+    pub claim_period: i64, // Claim window after resolution in seconds (0 = DEFAULT_CLAIM_PERIOD)
+    pub paused: u8,        // Globally paused instructions (PAUSE_* bitmask, 0 = none)
+
+    // Withdrawal policy (see configure_withdrawals / queue_withdrawal)
+    pub withdrawal_delay: i64,     // Timelock between queue and execute in seconds (0 = DEFAULT_WITHDRAWAL_DELAY)
+    pub withdrawal_epoch_cap: u64, // Max lamports withdrawn per Solana epoch (0 = no cap)
+    pub withdrawal_epoch: u64,     // Epoch that epoch_withdrawn refers to
+    pub epoch_withdrawn: u64,      // Lamports withdrawn during withdrawal_epoch
+    pub total_withdrawn: u64,      // Lamports withdrawn over the treasury's lifetime
+    pub next_withdrawal_id: u64,   // Id (PDA seed) of the next queued withdrawal
+    pub withdrawal_recipients: [Pubkey; MAX_WITHDRAWAL_RECIPIENTS], // Allowed recipients (first withdrawal_recipient_count used)
+    pub withdrawal_recipient_count: u8, // Number of allowlisted recipients
+
+    // Fee ledger by source (see record_fee). Fees collected before these fields
+    // existed are only in total_fees.
+    pub creation_fees: u64,   // Market creation fees
+    pub trade_fees: u64,      // Buy / switch_side trade fees
+    pub completion_fees: u64, // Resolution completion fees
+
+    pub platform_recipient: Pubkey, // Receives platform token allocations (default = PNL_WALLET)
+
+    // Founder verification (see configure_founder_verification / verify_founder)
+    pub founder_verifier: Pubkey,     // May verify founders besides the admin (default = admin only)
+    pub verification_min_target: u64, // Target pool from which create_market needs a verified founder (0 = never)
+}
+
+/// Where a fee paid into the treasury came from
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FeeSource {
+    Creation,
+    Trade,
+    Completion,
 }
 
 impl Treasury {
-    pub const INIT_SPACE: usize = 32 + 8 + 1;
+    pub const INIT_SPACE: usize = 32 + 8 + 1 + 8 + 1
+        + 8 + 8 + 8 + 8 + 8 + 8
+        + 32 * MAX_WITHDRAWAL_RECIPIENTS + 1
+        + 8 + 8 + 8
+        + 32
+        + 32 + 8;
+
+    /// Claim window applied to markets resolved now
+    pub fn effective_claim_period(&self) -> i64 {
+        if self.claim_period > 0 {
+            self.claim_period
+        } else {
+            crate::constants::DEFAULT_CLAIM_PERIOD
+        }
+    }
+
+    /// Wallet that receives platform token allocations (claim_platform_tokens)
+    pub fn effective_platform_recipient(&self) -> Pubkey {
+        if self.platform_recipient != Pubkey::default() {
+            self.platform_recipient
+        } else {
+            Pubkey::from_str(PNL_WALLET).unwrap()
+        }
+    }
+
+    /// Timelock applied to withdrawals queued now
+    pub fn effective_withdrawal_delay(&self) -> i64 {
+        if self.withdrawal_delay > 0 {
+            self.withdrawal_delay
+        } else {
+            DEFAULT_WITHDRAWAL_DELAY
+        }
+    }
+
+    /// Allowlisted withdrawal recipients
+    pub fn withdrawal_allowlist(&self) -> &[Pubkey] {
+        let count = (self.withdrawal_recipient_count as usize).min(MAX_WITHDRAWAL_RECIPIENTS);
+        &self.withdrawal_recipients[..count]
+    }
+
+    /// Replace the withdrawal allowlist (caller checks the length), zeroing unused slots
+    pub fn set_withdrawal_allowlist(&mut self, allowlist: &[Pubkey]) {
+        self.withdrawal_recipients = [Pubkey::default(); MAX_WITHDRAWAL_RECIPIENTS];
+        self.withdrawal_recipients[..allowlist.len()].copy_from_slice(allowlist);
+        self.withdrawal_recipient_count = allowlist.len() as u8;
+    }
+
+    /// Whether `recipient` is on the withdrawal allowlist
+    pub fn is_allowed_recipient(&self, recipient: &Pubkey) -> bool {
+        self.withdrawal_allowlist().contains(recipient)
+    }
+
+    /// Record a fee transferred into the treasury in total_fees and its source counter
+    pub fn record_fee(&mut self, source: FeeSource, amount: u64) -> Result<()> {
+        let counter = match source {
+            FeeSource::Creation => &mut self.creation_fees,
+            FeeSource::Trade => &mut self.trade_fees,
+            FeeSource::Completion => &mut self.completion_fees,
+        };
+        *counter = counter.checked_add(amount).ok_or(ErrorCode::MathError)?;
+        self.total_fees = self
+            .total_fees
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathError)?;
+        Ok(())
+    }
+
+    /// Whether a market with `target_pool` needs a verified FounderProfile
+    pub fn requires_verified_founder(&self, target_pool: u64) -> bool {
+        self.verification_min_target > 0 && target_pool >= self.verification_min_target
+    }
+
+    /// Whether any of the PAUSE_* `flags` is paused platform-wide
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.paused & flags != 0
+    }
 }
@@ -0,0 +1,226 @@
+use anchor_lang::prelude::*;
+use crate::constants::{
+    DEFAULT_VESTING_DURATION, MAX_VESTING_DURATION, MIN_VESTING_DURATION, VESTING_MONTH_SECONDS,
+};
+
+/// Unlock curve for vested allocations
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum VestingCurve {
+    /// Continuous unlock from start (or from the cliff) until duration
+    #[default]
+    Linear,
+    /// Nothing unlocks until duration, then everything at once
+    Cliff,
+    /// Equal unlocks at the end of every 30-day month until duration
+    MonthlyStep,
+}
+
+/// Where revoked team tokens and founder SOL go (see revoke_vesting)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RevokeDestination {
+    /// Transfer unvested tokens / SOL to the treasury
+    Treasury,
+    /// Add unvested tokens to the YES voter allocation and unvested SOL to
+    /// the clawback SOL pool
+    YesVoters,
+}
+
+/// Vesting schedule shared by team token and founder SOL vesting
+///
+/// Stored on Market (set by the founder at create_market, adjustable via
+/// configure_vesting before the first trade).
+/// An all-zero schedule means "use the default": 12 × 30 days linear, no cliff.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct VestingSchedule {
+    /// Unlock curve
+    pub curve: VestingCurve,
+
+    /// Seconds after start before anything unlocks (Linear / MonthlyStep only).
+    /// Amounts accrued during the cliff unlock when it ends.
+    pub cliff_seconds: i64,
+
+    /// Total vesting duration in seconds (0 = default schedule)
+    pub duration_seconds: i64,
+}
+
+impl VestingSchedule {
+    /// Serialized size: 1 (curve) + 8 (cliff_seconds) + 8 (duration_seconds)
+    pub const SIZE: usize = 1 + 8 + 8;
+
+    /// Default schedule used when none was configured (12 months linear, no cliff)
+    pub const DEFAULT: VestingSchedule = VestingSchedule {
+        curve: VestingCurve::Linear,
+        cliff_seconds: 0,
+        duration_seconds: DEFAULT_VESTING_DURATION,
+    };
+
+    /// Schedule actually applied (zeroed schedules fall back to DEFAULT)
+    pub fn effective(&self) -> VestingSchedule {
+        if self.duration_seconds == 0 {
+            Self::DEFAULT
+        } else {
+            *self
+        }
+    }
+
+    /// Whether the schedule is within platform bounds (an all-zero schedule is valid)
+    pub fn is_valid(&self) -> bool {
+        if *self == Self::default() {
+            return true;
+        }
+
+        if !(MIN_VESTING_DURATION..=MAX_VESTING_DURATION).contains(&self.duration_seconds) {
+            return false;
+        }
+
+        match self.curve {
+            VestingCurve::Linear => {
+                self.cliff_seconds >= 0 && self.cliff_seconds < self.duration_seconds
+            }
+            // The duration itself is the cliff
+            VestingCurve::Cliff => self.cliff_seconds == 0,
+            VestingCurve::MonthlyStep => {
+                self.cliff_seconds >= 0
+                    && self.cliff_seconds < self.duration_seconds
+                    && self.duration_seconds % VESTING_MONTH_SECONDS == 0
+            }
+        }
+    }
+
+    /// Amount of `total` unlocked at `now` for vesting that started at `start`
+    pub fn unlocked_amount(&self, total: u64, start: i64, now: i64) -> u64 {
+        let schedule = self.effective();
+        let elapsed = now.saturating_sub(start);
+
+        // Nothing before start, everything after the full duration
+        if elapsed <= 0 {
+            return 0;
+        }
+        if elapsed >= schedule.duration_seconds {
+            return total;
+        }
+
+        match schedule.curve {
+            VestingCurve::Cliff => 0,
+            VestingCurve::Linear => {
+                if elapsed < schedule.cliff_seconds {
+                    return 0;
+                }
+                // total * elapsed / duration
+                (total as u128 * elapsed as u128 / schedule.duration_seconds as u128) as u64
+            }
+            VestingCurve::MonthlyStep => {
+                if elapsed < schedule.cliff_seconds {
+                    return 0;
+                }
+                // total * completed_months / total_months
+                let months = elapsed / VESTING_MONTH_SECONDS;
+                let total_months = schedule.duration_seconds / VESTING_MONTH_SECONDS;
+                (total as u128 * months as u128 / total_months as u128) as u64
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MONTH: i64 = VESTING_MONTH_SECONDS;
+    const TOTAL: u64 = 1_200_000;
+
+    #[test]
+    fn test_zeroed_schedule_is_default_linear() {
+        let schedule = VestingSchedule::default();
+        assert!(schedule.is_valid());
+        assert_eq!(schedule.effective(), VestingSchedule::DEFAULT);
+
+        // Half way through 12 months → half unlocked
+        assert_eq!(schedule.unlocked_amount(TOTAL, 0, 6 * MONTH), TOTAL / 2);
+        assert_eq!(schedule.unlocked_amount(TOTAL, 0, 12 * MONTH), TOTAL);
+    }
+
+    #[test]
+    fn test_nothing_unlocked_before_start() {
+        let schedule = VestingSchedule::DEFAULT;
+        assert_eq!(schedule.unlocked_amount(TOTAL, 100, 50), 0);
+        assert_eq!(schedule.unlocked_amount(TOTAL, 100, 100), 0);
+    }
+
+    #[test]
+    fn test_linear_with_cliff_catches_up() {
+        let schedule = VestingSchedule {
+            curve: VestingCurve::Linear,
+            cliff_seconds: 3 * MONTH,
+            duration_seconds: 12 * MONTH,
+        };
+        assert!(schedule.is_valid());
+
+        assert_eq!(schedule.unlocked_amount(TOTAL, 0, 3 * MONTH - 1), 0);
+        // At the cliff the first 3 months unlock at once
+        assert_eq!(schedule.unlocked_amount(TOTAL, 0, 3 * MONTH), TOTAL / 4);
+    }
+
+    #[test]
+    fn test_cliff_unlocks_everything_at_duration() {
+        let schedule = VestingSchedule {
+            curve: VestingCurve::Cliff,
+            cliff_seconds: 0,
+            duration_seconds: 6 * MONTH,
+        };
+        assert!(schedule.is_valid());
+
+        assert_eq!(schedule.unlocked_amount(TOTAL, 0, 6 * MONTH - 1), 0);
+        assert_eq!(schedule.unlocked_amount(TOTAL, 0, 6 * MONTH), TOTAL);
+    }
+
+    #[test]
+    fn test_monthly_step_unlocks_whole_months() {
+        let schedule = VestingSchedule {
+            curve: VestingCurve::MonthlyStep,
+            cliff_seconds: 0,
+            duration_seconds: 12 * MONTH,
+        };
+        assert!(schedule.is_valid());
+
+        assert_eq!(schedule.unlocked_amount(TOTAL, 0, MONTH - 1), 0);
+        assert_eq!(schedule.unlocked_amount(TOTAL, 0, MONTH), TOTAL / 12);
+        assert_eq!(schedule.unlocked_amount(TOTAL, 0, 2 * MONTH - 1), TOTAL / 12);
+        assert_eq!(schedule.unlocked_amount(TOTAL, 0, 11 * MONTH), TOTAL * 11 / 12);
+    }
+
+    #[test]
+    fn test_invalid_schedules_rejected() {
+        // Too short
+        assert!(!VestingSchedule {
+            curve: VestingCurve::Linear,
+            cliff_seconds: 0,
+            duration_seconds: MIN_VESTING_DURATION - 1,
+        }
+        .is_valid());
+
+        // Cliff longer than duration
+        assert!(!VestingSchedule {
+            curve: VestingCurve::Linear,
+            cliff_seconds: 12 * MONTH,
+            duration_seconds: 12 * MONTH,
+        }
+        .is_valid());
+
+        // Monthly steps need whole months
+        assert!(!VestingSchedule {
+            curve: VestingCurve::MonthlyStep,
+            cliff_seconds: 0,
+            duration_seconds: 12 * MONTH + 1,
+        }
+        .is_valid());
+
+        // Cliff curve ignores cliff_seconds, so it must be zero
+        assert!(!VestingSchedule {
+            curve: VestingCurve::Cliff,
+            cliff_seconds: MONTH,
+            duration_seconds: 12 * MONTH,
+        }
+        .is_valid());
+    }
+}
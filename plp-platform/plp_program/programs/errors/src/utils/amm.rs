@@ -110,6 +110,74 @@ pub fn calculate_shares_from_sol(
     }
 }
 
+/// Calculate SOL returned when selling shares back into the AMM
+///
+/// Inverse of `calculate_shares_from_sol`:
+/// - Current: x_old * y_old = k
+/// - User returns: shares to their side's pool
+/// - User receives: SOL removed from the opposite pool
+///
+/// For selling YES (returning to x pool):
+/// - x_new = x_old + shares
+/// - y_new = k / x_new
+/// - sol_out = y_old - y_new
+///
+/// Args:
+/// - yes_pool: Current YES token reserves (scaled by 1e9)
+/// - no_pool: Current NO token reserves (scaled by 1e9)
+/// - shares: Number of shares to sell
+/// - sell_yes: true if selling YES, false if selling NO
+///
+/// Returns: SOL value of the shares in lamports (before fees)
+pub fn calculate_sol_from_shares(
+    yes_pool: u64,
+    no_pool: u64,
+    shares: u64,
+    sell_yes: bool,
+) -> Result<u64, ErrorCode> {
+    if yes_pool == 0 || no_pool == 0 {
+        return Err(ErrorCode::MathError);
+    }
+    if shares == 0 {
+        return Ok(0);
+    }
+
+    let k = (yes_pool as u128)
+        .checked_mul(no_pool as u128)
+        .ok_or(ErrorCode::MathError)?;
+
+    // Pool receiving the shares and pool paying out SOL
+    let (share_pool, sol_pool) = if sell_yes {
+        (yes_pool, no_pool)
+    } else {
+        (no_pool, yes_pool)
+    };
+
+    // share_pool_new = share_pool_old + shares
+    let share_pool_new = (share_pool as u128)
+        .checked_add(shares as u128)
+        .ok_or(ErrorCode::MathError)?;
+
+    // sol_pool_new = k / share_pool_new (rounded up so the pool never loses value)
+    let sol_pool_new = k
+        .checked_add(share_pool_new - 1)
+        .ok_or(ErrorCode::MathError)?
+        .checked_div(share_pool_new)
+        .ok_or(ErrorCode::MathError)?;
+
+    // sol_out = sol_pool_old - sol_pool_new
+    let sol_out = (sol_pool as u128)
+        .checked_sub(sol_pool_new)
+        .ok_or(ErrorCode::MathError)?;
+
+    // Keep minimum liquidity in the paying pool (same floor as buys)
+    if sol_pool_new < 10_000_000 {
+        return Err(ErrorCode::InsufficientBalance);
+    }
+
+    Ok(sol_out as u64)
+}
+
 /// Get current price of YES in terms of probability (0 to 1, scaled by 1e9)
 ///
 /// Price = NO_pool / (YES_pool + NO_pool)
@@ -208,4 +276,21 @@ mod tests {
         let diff = if k > k_new { k - k_new } else { k_new - k };
         assert!(diff < k / 1000); // Within 0.1% tolerance
     }
+
+    #[test]
+    fn test_sell_after_buy_round_trips() {
+        let yes_pool = 1_000_000_000_000;
+        let no_pool = 1_000_000_000_000;
+        let sol_in = 100_000_000_000;
+
+        // Buy YES, then sell the same shares straight back
+        let shares = calculate_shares_from_sol(yes_pool, no_pool, sol_in, true).unwrap();
+        let yes_pool_new = yes_pool - shares;
+        let no_pool_new = no_pool + sol_in;
+
+        let sol_out = calculate_sol_from_shares(yes_pool_new, no_pool_new, shares, true).unwrap();
+
+        // Round trip returns what was paid (within rounding dust)
+        assert!(sol_out.abs_diff(sol_in) < 1_000);
+    }
 }
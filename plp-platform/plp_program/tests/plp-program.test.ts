@@ -24,6 +24,8 @@ describe("PLP Prediction Market Program", () => {
   const ipfsCid = "QmTest123456789abcdefghijklmnopqrstuvwxyz";
   const targetPool = new BN(5 * LAMPORTS_PER_SOL); // 5 SOL
   const metadataUri = "https://ipfs.io/ipfs/QmTest123456789";
+  // All-zero vesting schedule = default (12 months linear, no cliff)
+  const defaultSchedule = { curve: { linear: {} }, cliffSeconds: new BN(0), durationSeconds: new BN(0) };
 
   // Helper function to airdrop SOL
   async function airdrop(publicKey: PublicKey, amount: number = 10) {
@@ -121,7 +123,7 @@ describe("PLP Prediction Market Program", () => {
       const treasuryBalanceBefore = await getBalance(treasury);
 
       const tx = await program.methods
-        .createMarket(ipfsCid, targetPool, expiryTime, metadataUri)
+        .createMarket(ipfsCid, targetPool, expiryTime, metadataUri, defaultSchedule, defaultSchedule)
         .accounts({
           market: marketPda,
           treasury: treasury,
@@ -171,7 +173,7 @@ describe("PLP Prediction Market Program", () => {
 
       try {
         await program.methods
-          .createMarket("invalid", invalidTarget, expiryTime, metadataUri)
+          .createMarket("invalid", invalidTarget, expiryTime, metadataUri, defaultSchedule, defaultSchedule)
           .accounts({
             market: invalidMarket,
             treasury: treasury,
@@ -398,7 +400,7 @@ describe("PLP Prediction Market Program", () => {
       const expiryTime = new BN(Math.floor(Date.now() / 1000) + 5); // Expires in 5 seconds
 
       await program.methods
-        .createMarket(newIpfsCid, targetPool, expiryTime, metadataUri)
+        .createMarket(newIpfsCid, targetPool, expiryTime, metadataUri, defaultSchedule, defaultSchedule)
         .accounts({
           market: newMarketPda,
           treasury: treasury,
@@ -509,37 +511,89 @@ describe("PLP Prediction Market Program", () => {
   });
 
   describe("8. Treasury Management", () => {
-    it("Should allow admin to withdraw fees", async () => {
-      const treasuryBefore = await program.account.treasury.fetch(treasury);
+    it("Should queue a timelocked withdrawal to an allowlisted recipient", async () => {
+      const withdrawalDelay = new BN(60 * 60); // 1 hour (MIN_WITHDRAWAL_DELAY)
       const withdrawAmount = new BN(10_000_000); // 0.01 SOL
 
-      if (treasuryBefore.totalFees.gte(withdrawAmount)) {
-        const recipientBefore = await getBalance(provider.wallet.publicKey);
+      // Allowlist the admin wallet, no epoch cap
+      await program.methods
+        .configureWithdrawals(withdrawalDelay, new BN(0), [provider.wallet.publicKey])
+        .accounts({
+          treasury: treasury,
+          admin: provider.wallet.publicKey,
+          adminCouncil: null,
+          adminProposal: null,
+        })
+        .rpc();
 
-        const tx = await program.methods
-          .withdrawFees(withdrawAmount)
+      const treasuryBefore = await program.account.treasury.fetch(treasury);
+      const [withdrawalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("withdrawal"), treasuryBefore.nextWithdrawalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      const tx = await program.methods
+        .queueWithdrawal(withdrawAmount)
+        .accounts({
+          treasury: treasury,
+          withdrawal: withdrawalPda,
+          admin: provider.wallet.publicKey,
+          recipient: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+          adminCouncil: null,
+          adminProposal: null,
+        })
+        .rpc();
+
+      console.log("  ✅ Withdrawal queued:", tx);
+
+      const withdrawal = await program.account.pendingWithdrawal.fetch(withdrawalPda);
+      assert.equal(withdrawal.recipient.toString(), provider.wallet.publicKey.toString());
+      assert.equal(withdrawal.amount.toString(), withdrawAmount.toString());
+      assert.equal(
+        withdrawal.executableAt.sub(withdrawal.queuedAt).toString(),
+        withdrawalDelay.toString()
+      );
+
+      // Executing before the delay has passed must fail
+      const [stakingPool] = PublicKey.findProgramAddressSync(
+        [Buffer.from("staking_pool")],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .executeWithdrawal()
           .accounts({
             treasury: treasury,
+            withdrawal: withdrawalPda,
             admin: provider.wallet.publicKey,
             recipient: provider.wallet.publicKey,
-            systemProgram: SystemProgram.programId,
+            stakingPool: stakingPool,
+            adminCouncil: null,
           })
           .rpc();
 
-        console.log("  ✅ Fees withdrawn:", tx);
-
-        const treasuryAfter = await program.account.treasury.fetch(treasury);
-        const recipientAfter = await getBalance(provider.wallet.publicKey);
+        assert.fail("Should have failed with WithdrawalTimelocked");
+      } catch (error) {
+        assert.include(error.toString(), "WithdrawalTimelocked");
+        console.log("  ✅ Correctly rejected execution before the timelock");
+      }
 
-        assert.isTrue(
-          recipientAfter > recipientBefore,
-          "Recipient should receive funds"
-        );
+      // Cancel so the pending withdrawal does not linger
+      await program.methods
+        .cancelWithdrawal()
+        .accounts({
+          treasury: treasury,
+          withdrawal: withdrawalPda,
+          admin: provider.wallet.publicKey,
+          adminCouncil: null,
+          adminProposal: null,
+        })
+        .rpc();
 
-        console.log("  💰 Withdrawn:", withdrawAmount.toString(), "lamports");
-      } else {
-        console.log("  ℹ️  Not enough fees to test withdrawal");
-      }
+      const closed = await provider.connection.getAccountInfo(withdrawalPda);
+      assert.isNull(closed, "Cancelled withdrawal should be closed");
     });
   });
 });
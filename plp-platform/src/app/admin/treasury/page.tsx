@@ -15,11 +15,15 @@ import { Input } from '@/components/ui/input';
 import { Label } from '@/components/ui/label';
 import { Loader2, Wallet, Shield, ArrowDownToLine, Coins, Target, AlertTriangle } from 'lucide-react';
 import { usePlatformTokens } from '@/lib/hooks/usePlatformTokens';
-import { useEmergencyDrain } from '@/lib/hooks/useEmergencyDrain';
+import { useEmergencyRefund } from '@/lib/hooks/useEmergencyRefund';
 
 interface TreasuryState {
   admin: string;
   totalFees: number;
+  creationFees: number;
+  tradeFees: number;
+  completionFees: number;
+  totalWithdrawn: number;
   balance: number;
   bump: number;
 }
@@ -40,20 +44,21 @@ export default function TreasuryAdminPage() {
   const { wallets } = useWallets();
   const { signAndSendTransaction } = useSignAndSendTransaction();
   const { claimPlatformTokens, isClaiming } = usePlatformTokens();
-  const { drainVault, isDraining } = useEmergencyDrain();
+  const { refundMarket, isRefunding } = useEmergencyRefund();
 
   const [treasury, setTreasury] = useState<TreasuryState | null>(null);
   const [loading, setLoading] = useState(false);
   const [newAdminAddress, setNewAdminAddress] = useState('');
   const [withdrawAmount, setWithdrawAmount] = useState('');
+  const [withdrawalId, setWithdrawalId] = useState('');
   const [recipientAddress, setRecipientAddress] = useState('');
   const [marketsWithTokens, setMarketsWithTokens] = useState<MarketWithTokens[]>([]);
   const [loadingMarkets, setLoadingMarkets] = useState(false);
   const [claimingMarket, setClaimingMarket] = useState<string | null>(null);
 
-  // Emergency drain state
-  const [drainMarketAddress, setDrainMarketAddress] = useState('');
-  const [drainFounderAddress, setDrainFounderAddress] = useState('');
+  // Emergency refund state
+  const [refundMarketAddress, setRefundMarketAddress] = useState('');
+  const [refundReason, setRefundReason] = useState('');
 
   // Platform wallet for 2% tokens (hardcoded in program)
   const PLATFORM_WALLET = '3MihVtsLsVuEccpmz4YG72Cr8CJWf1evRorTPdPiHeEQ';
@@ -77,15 +82,34 @@ export default function TreasuryAdminPage() {
 
       // Parse treasury account data
       // Layout: [discriminator: 8 bytes][admin: 32 bytes][total_fees: 8 bytes][bump: 1 byte]
+      // ... [total_withdrawn @ 90][next_withdrawal_id @ 98][recipients: 32 * 5 @ 106][recipient_count @ 266]
+      // [creation_fees @ 267][trade_fees @ 275][completion_fees @ 283] (zero on unmigrated treasuries)
       const data = accountInfo.data;
       const admin = new PublicKey(data.slice(8, 40)).toBase58();
       const totalFees = Number(data.readBigUInt64LE(40));
       const bump = data[48];
       const balance = accountInfo.lamports;
 
+      let totalWithdrawn = 0;
+      let creationFees = 0;
+      let tradeFees = 0;
+      let completionFees = 0;
+      if (data.length >= 98) {
+        totalWithdrawn = Number(data.readBigUInt64LE(90));
+      }
+      if (data.length >= 291) {
+        creationFees = Number(data.readBigUInt64LE(267));
+        tradeFees = Number(data.readBigUInt64LE(275));
+        completionFees = Number(data.readBigUInt64LE(283));
+      }
+
       setTreasury({
         admin,
         totalFees,
+        creationFees,
+        tradeFees,
+        completionFees,
+        totalWithdrawn,
         balance,
         bump,
       });
@@ -271,51 +295,57 @@ export default function TreasuryAdminPage() {
     }
   };
 
-  // Emergency drain vault (admin only)
-  const handleEmergencyDrain = async () => {
-    if (!drainMarketAddress || !drainFounderAddress) {
-      alert('Please enter both market address and founder address');
+  // Emergency refund (admin only)
+  const handleEmergencyRefund = async () => {
+    if (!refundMarketAddress || !refundReason) {
+      alert('Please enter both market address and reason');
       return;
     }
 
     try {
-      const result = await drainVault({
-        marketAddress: drainMarketAddress,
-        founderAddress: drainFounderAddress,
+      const result = await refundMarket({
+        marketAddress: refundMarketAddress,
+        reason: refundReason,
       });
 
       if (result.success) {
-        alert(`Vault drained successfully! Signature: ${result.signature}`);
-        setDrainMarketAddress('');
-        setDrainFounderAddress('');
+        alert(`Market switched to refund mode! Signature: ${result.signature}`);
+        setRefundMarketAddress('');
+        setRefundReason('');
       } else {
         throw new Error(result.error);
       }
     } catch (error: any) {
-      console.error('Failed to drain vault:', error);
-      alert(`Failed to drain vault: ${error.message}`);
+      console.error('Failed to trigger emergency refund:', error);
+      alert(`Failed to trigger emergency refund: ${error.message}`);
     }
   };
 
-  // Withdraw fees (admin only)
-  const handleWithdrawFees = async () => {
-    if (!withdrawAmount || !recipientAddress) {
+  // Timelocked fee withdrawals (admin only): queue, then execute after the delay
+  const handleWithdrawFees = async (action: 'queue' | 'execute' | 'cancel') => {
+    if (action === 'queue' && (!withdrawAmount || !recipientAddress)) {
       alert('Please enter amount and recipient address');
       return;
     }
+    if (action !== 'queue' && !withdrawalId) {
+      alert('Please enter the withdrawal id');
+      return;
+    }
 
     try {
       setLoading(true);
 
-      const amountLamports = parseFloat(withdrawAmount) * 1e9;
+      const amountLamports = parseFloat(withdrawAmount || '0') * 1e9;
 
       const response = await fetch('/api/treasury/withdraw-fees', {
         method: 'POST',
         headers: { 'Content-Type': 'application/json' },
         body: JSON.stringify({
+          action,
           admin: primaryWallet!.address,
           recipient: recipientAddress,
           amount: Math.floor(amountLamports),
+          withdrawalId,
           network,
         }),
       });
@@ -339,7 +369,7 @@ export default function TreasuryAdminPage() {
       });
 
       const signature = bs58.encode(txResult.signature);
-      console.log('Fees withdrawn:', signature);
+      console.log(`Withdrawal ${action}:`, signature);
 
       // Wait for confirmation
       const connection = await getSolanaConnection(network);
@@ -348,9 +378,14 @@ export default function TreasuryAdminPage() {
       await fetchTreasury();
       setWithdrawAmount('');
       setRecipientAddress('');
-      alert('Fees withdrawn successfully!');
+      setWithdrawalId('');
+      alert(
+        action === 'queue'
+          ? `Withdrawal #${result.data.withdrawalId} queued - execute it once the timelock has passed`
+          : `Withdrawal #${result.data.withdrawalId} ${action === 'execute' ? 'executed' : 'cancelled'}`
+      );
     } catch (error: any) {
-      console.error('Failed to withdraw fees:', error);
+      console.error(`Failed to ${action} withdrawal:`, error);
       alert(`Failed: ${error.message}`);
     } finally {
       setLoading(false);
@@ -436,6 +471,22 @@ export default function TreasuryAdminPage() {
                     {(treasury.totalFees / 1e9).toFixed(4)} SOL
                   </span>
                 </div>
+                <div className="flex justify-between text-sm text-gray-400">
+                  <span>Creation Fees:</span>
+                  <span>{(treasury.creationFees / 1e9).toFixed(4)} SOL</span>
+                </div>
+                <div className="flex justify-between text-sm text-gray-400">
+                  <span>Trade Fees:</span>
+                  <span>{(treasury.tradeFees / 1e9).toFixed(4)} SOL</span>
+                </div>
+                <div className="flex justify-between text-sm text-gray-400">
+                  <span>Completion Fees:</span>
+                  <span>{(treasury.completionFees / 1e9).toFixed(4)} SOL</span>
+                </div>
+                <div className="flex justify-between text-sm text-gray-400">
+                  <span>Total Withdrawn:</span>
+                  <span>{(treasury.totalWithdrawn / 1e9).toFixed(4)} SOL</span>
+                </div>
               </div>
             ) : (
               <div className="text-center py-8 space-y-4">
@@ -516,7 +567,7 @@ export default function TreasuryAdminPage() {
                   Withdraw Fees
                 </CardTitle>
                 <CardDescription className="text-gray-400">
-                  Withdraw collected platform fees
+                  Queue a timelocked withdrawal to an allowlisted recipient, then execute it after the delay
                 </CardDescription>
               </CardHeader>
               <CardContent className="space-y-4">
@@ -544,19 +595,46 @@ export default function TreasuryAdminPage() {
                   />
                 </div>
                 <Button
-                  onClick={handleWithdrawFees}
+                  onClick={() => handleWithdrawFees('queue')}
                   disabled={loading || !withdrawAmount || !recipientAddress}
                   className="w-full bg-gradient-to-r from-green-500 to-cyan-500"
                 >
                   {loading ? (
                     <>
                       <Loader2 className="w-4 h-4 mr-2 animate-spin" />
-                      Withdrawing...
+                      Processing...
                     </>
                   ) : (
-                    'Withdraw Fees'
+                    'Queue Withdrawal'
                   )}
                 </Button>
+                <div className="space-y-2">
+                  <Label className="text-gray-300">Queued Withdrawal ID</Label>
+                  <Input
+                    type="number"
+                    value={withdrawalId}
+                    onChange={(e) => setWithdrawalId(e.target.value)}
+                    placeholder="0"
+                    className="bg-gray-700 border-gray-600 text-white"
+                  />
+                </div>
+                <div className="flex gap-2">
+                  <Button
+                    onClick={() => handleWithdrawFees('execute')}
+                    disabled={loading || !withdrawalId}
+                    className="flex-1 bg-gradient-to-r from-green-500 to-cyan-500"
+                  >
+                    Execute
+                  </Button>
+                  <Button
+                    onClick={() => handleWithdrawFees('cancel')}
+                    disabled={loading || !withdrawalId}
+                    variant="outline"
+                    className="flex-1 border-gray-600 text-gray-300"
+                  >
+                    Cancel
+                  </Button>
+                </div>
               </CardContent>
             </Card>
 
@@ -650,55 +728,56 @@ export default function TreasuryAdminPage() {
               </CardContent>
             </Card>
 
-            {/* Emergency Drain Vault */}
+            {/* Emergency Refund */}
             <Card className="bg-red-900/20 border-red-700">
               <CardHeader>
                 <CardTitle className="text-white flex items-center gap-2">
                   <AlertTriangle className="w-5 h-5" />
-                  Emergency Vault Drain
+                  Emergency Refund
                 </CardTitle>
                 <CardDescription className="text-gray-400">
-                  Drain stuck SOL from market vaults back to founder (Admin only)
+                  Force an unresolved market into Refund so every trader can reclaim their SOL (Admin only)
                 </CardDescription>
               </CardHeader>
               <CardContent className="space-y-4">
                 <div className="space-y-2">
                   <Label className="text-gray-300">Market Address</Label>
                   <Input
-                    value={drainMarketAddress}
-                    onChange={(e) => setDrainMarketAddress(e.target.value)}
+                    value={refundMarketAddress}
+                    onChange={(e) => setRefundMarketAddress(e.target.value)}
                     placeholder="Enter market address..."
                     className="bg-gray-700 border-gray-600 text-white"
                   />
                 </div>
                 <div className="space-y-2">
-                  <Label className="text-gray-300">Founder Address</Label>
+                  <Label className="text-gray-300">Reason</Label>
                   <Input
-                    value={drainFounderAddress}
-                    onChange={(e) => setDrainFounderAddress(e.target.value)}
-                    placeholder="Enter founder address..."
+                    value={refundReason}
+                    onChange={(e) => setRefundReason(e.target.value)}
+                    maxLength={200}
+                    placeholder="Shown to traders (max 200 characters)..."
                     className="bg-gray-700 border-gray-600 text-white"
                   />
                 </div>
                 <Button
-                  onClick={handleEmergencyDrain}
-                  disabled={isDraining || !drainMarketAddress || !drainFounderAddress}
+                  onClick={handleEmergencyRefund}
+                  disabled={isRefunding || !refundMarketAddress || !refundReason}
                   className="w-full bg-gradient-to-r from-red-500 to-orange-500 hover:from-red-600 hover:to-orange-600"
                 >
-                  {isDraining ? (
+                  {isRefunding ? (
                     <>
                       <Loader2 className="w-4 h-4 mr-2 animate-spin" />
-                      Draining Vault...
+                      Switching to Refund...
                     </>
                   ) : (
                     <>
                       <AlertTriangle className="w-4 h-4 mr-2" />
-                      Drain Vault (Emergency Only)
+                      Refund Market (Emergency Only)
                     </>
                   )}
                 </Button>
                 <p className="text-xs text-yellow-400">
-                  ⚠️ Warning: This will drain all SOL from the vault to the founder&apos;s wallet. Only use in emergency situations.
+                  ⚠️ Warning: This resolves the market as Refund immediately and cannot be undone. Traders reclaim their investment minus trading fees.
                 </p>
               </CardContent>
             </Card>
@@ -741,7 +820,7 @@ export default function TreasuryAdminPage() {
             <p><strong>3. Lock Initial Wallet:</strong> Store securely as emergency recovery</p>
             <p><strong>4. Fee Collection:</strong> 5% completion fees accumulate in treasury PDA</p>
             <p><strong>5. Token Collection:</strong> 2% tokens allocated for platform, claimed by admin</p>
-            <p><strong>6. Withdrawal:</strong> Operational admin withdraws accumulated SOL fees and claims platform tokens anytime</p>
+            <p><strong>6. Withdrawal:</strong> Operational admin queues SOL fee withdrawals (timelocked, capped per epoch, allowlisted recipients) and claims platform tokens anytime</p>
           </CardContent>
         </Card>
       </div>
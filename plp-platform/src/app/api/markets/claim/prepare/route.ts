@@ -24,7 +24,7 @@ import {
   createAssociatedTokenAccountIdempotentInstruction,
   ASSOCIATED_TOKEN_PROGRAM_ID,
 } from '@solana/spl-token';
-import { getProgram, getPositionPDA } from '@/lib/anchor-program';
+import { getProgram, getPositionPDA, getReputationPDA } from '@/lib/anchor-program';
 import { createClientLogger } from '@/lib/logger';
 import { getSolanaConnection } from '@/lib/solana';
 
@@ -165,8 +165,9 @@ export async function POST(request: NextRequest) {
       );
     }
 
-    // Derive position PDA
+    // Derive position PDA and the user's reputation PDA (created on first claim)
     const [positionPda] = getPositionPDA(marketPubkey, userPubkey);
+    const [reputationPda] = getReputationPDA(userPubkey);
 
     logger.info('Fetching on-chain position account...', {
       positionPda: positionPda.toBase58(),
@@ -263,13 +264,13 @@ export async function POST(request: NextRequest) {
     const { PROGRAM_ID } = await import('@/config/solana');
 
     // For YesWins: need to derive Token2022 accounts
-    // For NoWins/Refund: token accounts are checked as UncheckedAccount, so we can use placeholders
+    // For NoWins/Refund: token accounts are optional, passing the program ID marks them as None
     const instructions = [];
 
-    let marketTokenAccount = userPubkey; // Placeholder for NoWins/Refund
-    let userTokenAccount = userPubkey;   // Placeholder for NoWins/Refund
-    let tokenMintAccount = userPubkey;   // Placeholder for NoWins/Refund
-    let tokenProgramId = TOKEN_2022_PROGRAM_ID; // Always Token2022 (Pump.fun standard)
+    let marketTokenAccount = PROGRAM_ID; // None for NoWins/Refund
+    let userTokenAccount = PROGRAM_ID;   // None for NoWins/Refund
+    let tokenMintAccount = PROGRAM_ID;   // None for NoWins/Refund
+    let tokenProgramId = PROGRAM_ID;     // None for NoWins/Refund
 
     if (marketAccount.resolution === 1 && marketAccount.tokenMint) {
       // YesWins - derive actual Token2022 ATAs
@@ -290,6 +291,7 @@ export async function POST(request: NextRequest) {
       );
 
       tokenMintAccount = marketAccount.tokenMint;
+      tokenProgramId = TOKEN_2022_PROGRAM_ID; // Pump.fun mints are Token2022
 
       // Create user token account if it doesn't exist (idempotent)
       const createUserTokenAccountIx = createAssociatedTokenAccountIdempotentInstruction(
@@ -313,17 +315,18 @@ export async function POST(request: NextRequest) {
     // Create claim instruction with proper accounts
     // Order must match ClaimRewards struct in Rust program:
     // 1. market, 2. position, 3. market_token_account, 4. user_token_account,
-    // 5. user, 6. system_program, 7. token_mint, 8. token_program
+    // 5. user, 6. system_program, 7. token_mint, 8. token_program, 9. reputation
     const claimIx = new TransactionInstruction({
       keys: [
         { pubkey: marketPubkey, isSigner: false, isWritable: true },   // market
-        { pubkey: positionPda, isSigner: false, isWritable: true },    // position (will be closed)
+        { pubkey: positionPda, isSigner: false, isWritable: true },    // position (closed unless YesWins)
         { pubkey: marketTokenAccount, isSigner: false, isWritable: true }, // market_token_account
         { pubkey: userTokenAccount, isSigner: false, isWritable: true },   // user_token_account
         { pubkey: userPubkey, isSigner: true, isWritable: true },      // user
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }, // system_program
         { pubkey: tokenMintAccount, isSigner: false, isWritable: false }, // token_mint
         { pubkey: tokenProgramId, isSigner: false, isWritable: false }, // token_program
+        { pubkey: reputationPda, isSigner: false, isWritable: true },   // reputation
       ],
       programId: PROGRAM_ID,
       data,
@@ -11,6 +11,7 @@ import {
   TransactionMessage,
   VersionedTransaction,
   ComputeBudgetProgram,
+  SystemProgram,
 } from '@solana/web3.js';
 import { createClientLogger } from '@/lib/logger';
 import { getSolanaConnection } from '@/lib/solana';
@@ -52,12 +53,26 @@ export async function POST(request: NextRequest) {
       PROGRAM_ID
     );
 
+    // Derive reputation PDA (records unclaimed refunds)
+    const [reputationPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('reputation'), userPubkey.toBytes()],
+      PROGRAM_ID
+    );
+
+    // Founder milestones (optional trailing account): YesWins positions can only
+    // close once every milestone is attested, which the program checks here
+    const [founderMilestonesPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('founder_milestones'), marketPubkey.toBytes()],
+      PROGRAM_ID
+    );
+
     logger.info('Position PDA derived', {
       positionPda: positionPda.toBase58(),
     });
 
     // Get connection
     const connection = await getSolanaConnection(network);
+    const founderMilestonesInfo = await connection.getAccountInfo(founderMilestonesPda);
 
     // Build close_position instruction manually
     // Calculate closePosition discriminator: sha256("global:close_position")[0..8]
@@ -78,7 +93,12 @@ export async function POST(request: NextRequest) {
       keys: [
         { pubkey: marketPubkey, isSigner: false, isWritable: false },        // market
         { pubkey: positionPda, isSigner: false, isWritable: true },          // position (will be closed)
+        { pubkey: reputationPda, isSigner: false, isWritable: true },        // reputation (created if needed)
         { pubkey: userPubkey, isSigner: true, isWritable: true },            // user (receives rent)
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }, // system_program
+        ...(founderMilestonesInfo
+          ? [{ pubkey: founderMilestonesPda, isSigner: false, isWritable: false }] // founder_milestones
+          : []),
       ],
       programId: PROGRAM_ID,
       data,
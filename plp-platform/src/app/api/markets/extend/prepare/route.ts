@@ -70,12 +70,14 @@ export async function POST(request: NextRequest) {
     const { PROGRAM_ID } = await import('@/config/solana');
 
     // Create instruction
-    // According to extend_market.rs, only market and founder are needed
+    // According to extend_market.rs: market, founder and treasury (pause flags)
+    const [treasuryPda] = getTreasuryPDA(network);
     const { TransactionInstruction } = await import('@solana/web3.js');
     const extendIx = new TransactionInstruction({
       keys: [
         { pubkey: marketPubkey, isSigner: false, isWritable: true },       // market
         { pubkey: founderPubkey, isSigner: true, isWritable: true },       // founder (only founder can extend)
+        { pubkey: treasuryPda, isSigner: false, isWritable: false },       // treasury (pause flags)
       ],
       programId: PROGRAM_ID,
       data,
@@ -20,12 +20,26 @@ import {
 } from '@solana/spl-token';
 import { createClientLogger } from '@/lib/logger';
 import { getSolanaConnection } from '@/lib/solana';
+import { getTreasuryPDA } from '@/lib/anchor-program';
 
 const logger = createClientLogger();
 
-// P&L Platform wallet (from constants in program)
+// P&L Platform wallet (from constants in program), used while Treasury.platform_recipient is unset
 const PNL_WALLET = '3MihVtsLsVuEccpmz4YG72Cr8CJWf1evRorTPdPiHeEQ';
 
+// Treasury layout: ... [recipients: 32 * 5 @ 106][recipient_count @ 266][creation/trade/completion fees @ 267][platform_recipient @ 291]
+const PLATFORM_RECIPIENT_OFFSET = 291;
+
+function readPlatformRecipient(data: Buffer): PublicKey {
+  if (data.length >= PLATFORM_RECIPIENT_OFFSET + 32) {
+    const recipient = new PublicKey(data.subarray(PLATFORM_RECIPIENT_OFFSET, PLATFORM_RECIPIENT_OFFSET + 32));
+    if (!recipient.equals(PublicKey.default)) {
+      return recipient;
+    }
+  }
+  return new PublicKey(PNL_WALLET);
+}
+
 export async function POST(request: NextRequest) {
   try {
     const body = await request.json();
@@ -53,7 +67,23 @@ export async function POST(request: NextRequest) {
     const marketPubkey = new PublicKey(marketAddress);
     const callerPubkey = new PublicKey(callerWallet);
     const tokenMintPubkey = new PublicKey(tokenMint);
-    const pnlWalletPubkey = new PublicKey(PNL_WALLET);
+
+    // Get connection
+    const connection = await getSolanaConnection(network);
+
+    // Get program ID
+    const { PROGRAM_ID } = await import('@/config/solana');
+
+    // Platform recipient is configured on the treasury (set_platform_recipient)
+    const [treasuryPda] = getTreasuryPDA(network);
+    const treasuryInfo = await connection.getAccountInfo(treasuryPda);
+    if (!treasuryInfo) {
+      return NextResponse.json(
+        { success: false, error: 'Treasury not initialized' },
+        { status: 400 }
+      );
+    }
+    const pnlWalletPubkey = readPlatformRecipient(treasuryInfo.data);
 
     // Get associated token accounts (Token2022 - Pump.fun uses Token2022)
     const marketTokenAccount = getAssociatedTokenAddressSync(
@@ -73,15 +103,9 @@ export async function POST(request: NextRequest) {
     logger.info('Token accounts derived', {
       marketTokenAccount: marketTokenAccount.toBase58(),
       pnlTokenAccount: pnlTokenAccount.toBase58(),
-      pnlWallet: PNL_WALLET,
+      pnlWallet: pnlWalletPubkey.toBase58(),
     });
 
-    // Get connection
-    const connection = await getSolanaConnection(network);
-
-    // Get program ID
-    const { PROGRAM_ID } = await import('@/config/solana');
-
     // Build claim_platform_tokens instruction manually
     // Calculate claimPlatformTokens discriminator: sha256("global:claim_platform_tokens")[0..8]
     const crypto = require('crypto');
@@ -105,6 +129,7 @@ export async function POST(request: NextRequest) {
         { pubkey: tokenMintPubkey, isSigner: false, isWritable: false },       // token_mint
         { pubkey: callerPubkey, isSigner: true, isWritable: true },            // caller (can be anyone)
         { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false }, // token_program (Token2022)
+        { pubkey: treasuryPda, isSigner: false, isWritable: false },           // treasury (platform recipient)
       ],
       programId: PROGRAM_ID,
       data,
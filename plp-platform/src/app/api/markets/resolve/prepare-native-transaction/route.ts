@@ -24,7 +24,7 @@ import {
   getAssociatedTokenAddress,
   createAssociatedTokenAccountInstruction,
 } from '@solana/spl-token';
-import { getTreasuryPDA, getProgramIdForNetwork, getMarketVaultPDA } from '@/lib/anchor-program';
+import { getTreasuryPDA, getProgramIdForNetwork, getMarketVaultPDA, getFounderReputationPDA } from '@/lib/anchor-program';
 import { derivePumpPDAs, PUMP_PROGRAM_ID } from '@/lib/pumpfun';
 import logger from '@/lib/logger';
 import { getSolanaConnection } from '@/lib/solana';
@@ -93,6 +93,9 @@ export async function POST(request: NextRequest) {
     // Derive Treasury PDA
     const [treasuryPda] = getTreasuryPDA(targetNetwork);
 
+    // Derive founder reputation PDA (updated on resolution)
+    const [founderReputationPda] = getFounderReputationPDA(founderPubkey, targetNetwork);
+
     // Derive Market Vault PDA (simple derivation from market address)
     const [marketVaultPda] = getMarketVaultPDA(marketPubkey, targetNetwork);
 
@@ -237,6 +240,8 @@ export async function POST(request: NextRequest) {
         { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
         // 22. rent
         { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        // 23. founder_reputation
+        { pubkey: founderReputationPda, isSigner: false, isWritable: true },
       ],
       programId: programId,
       data,
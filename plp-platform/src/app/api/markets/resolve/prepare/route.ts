@@ -20,7 +20,7 @@ import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddress,
 } from '@solana/spl-token';
-import { getTreasuryPDA, getMarketVaultPDA, getProgramIdForNetwork } from '@/lib/anchor-program';
+import { getTreasuryPDA, getMarketVaultPDA, getProgramIdForNetwork, getFounderReputationPDA } from '@/lib/anchor-program';
 import { derivePumpPDAs, PUMP_PROGRAM_ID } from '@/lib/pumpfun';
 import { createClientLogger } from '@/lib/logger';
 import { getSolanaConnection } from '@/lib/solana';
@@ -130,6 +130,14 @@ export async function POST(request: NextRequest) {
     // Get connection for the target network
     const connection = await getSolanaConnection(network);
 
+    // Founder reputation PDA (market.founder is the first field after the discriminator)
+    const marketInfo = await connection.getAccountInfo(marketPubkey);
+    if (!marketInfo) {
+      throw new Error('Market account not found');
+    }
+    const marketFounder = new PublicKey(marketInfo.data.subarray(8, 40));
+    const [founderReputationPda] = getFounderReputationPDA(marketFounder, targetNetwork);
+
     // Build resolve_market instruction manually (since IDL is incomplete)
     // Calculate resolveMarket discriminator: sha256("global:resolve_market")[0..8]
     const crypto = require('crypto');
@@ -192,6 +200,8 @@ export async function POST(request: NextRequest) {
         { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
         // 22. rent
         { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        // 23. founder_reputation (created on the founder's first resolution)
+        { pubkey: founderReputationPda, isSigner: false, isWritable: true },
       ],
       programId: programId,
       data,
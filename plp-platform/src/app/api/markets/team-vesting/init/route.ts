@@ -21,14 +21,14 @@ const logger = createClientLogger();
 export async function POST(request: NextRequest) {
   try {
     const body = await request.json();
-    const { marketAddress, teamWallet, callerWallet, totalTokenSupply, network } = body;
+    const { marketAddress, teamWallet, callerWallet, network } = body;
 
     // Validate inputs
-    if (!marketAddress || !teamWallet || !callerWallet || !totalTokenSupply) {
+    if (!marketAddress || !teamWallet || !callerWallet) {
       return NextResponse.json(
         {
           success: false,
-          error: 'Missing required fields: marketAddress, teamWallet, callerWallet, totalTokenSupply',
+          error: 'Missing required fields: marketAddress, teamWallet, callerWallet',
         },
         { status: 400 }
       );
@@ -38,7 +38,6 @@ export async function POST(request: NextRequest) {
       marketAddress,
       teamWallet,
       callerWallet,
-      totalTokenSupply,
       network,
     });
 
@@ -72,10 +71,10 @@ export async function POST(request: NextRequest) {
       .digest()
       .subarray(0, 8);
 
-    // Serialize instruction data: [discriminator(8 bytes), total_token_supply(u64)]
-    const data = Buffer.alloc(8 + 8);
+    // Serialize instruction data: [discriminator(8 bytes)]
+    // Team allocation is read on-chain from the market (recorded at resolution)
+    const data = Buffer.alloc(8);
     discriminator.copy(data, 0);
-    data.writeBigUInt64LE(BigInt(totalTokenSupply), 8);
 
     // Create instruction
     const { TransactionInstruction } = await import('@solana/web3.js');
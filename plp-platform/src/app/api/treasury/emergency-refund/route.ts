@@ -1,8 +1,9 @@
 /**
- * API endpoint for emergency vault drain
+ * API endpoint for emergency refund
  *
  * Only callable by treasury admin
- * Drains vault SOL back to market founder
+ * Moves vault SOL into the market and forces Refund resolution,
+ * so every position can reclaim its investment via claim_rewards
  */
 
 import { NextRequest, NextResponse } from 'next/server';
@@ -16,22 +17,30 @@ const logger = createClientLogger();
 export async function POST(request: NextRequest) {
   try {
     const body = await request.json();
-    const { marketAddress, founderAddress, adminWallet, network } = body;
+    const { marketAddress, reason, adminWallet, network } = body;
 
     // Validate inputs
-    if (!marketAddress || !founderAddress || !adminWallet) {
+    if (!marketAddress || !reason || !adminWallet) {
       return NextResponse.json(
         {
           success: false,
-          error: 'Missing required fields: marketAddress, founderAddress, adminWallet',
+          error: 'Missing required fields: marketAddress, reason, adminWallet',
         },
         { status: 400 }
       );
     }
 
-    logger.info('Emergency vault drain requested', {
+    const reasonBytes = Buffer.from(reason, 'utf8');
+    if (reasonBytes.length > 200) {
+      return NextResponse.json(
+        { success: false, error: 'Reason must be at most 200 bytes' },
+        { status: 400 }
+      );
+    }
+
+    logger.info('Emergency refund requested', {
       marketAddress,
-      founderAddress,
+      reason,
       adminWallet,
       network,
     });
@@ -41,7 +50,6 @@ export async function POST(request: NextRequest) {
     const programId = getProgramIdForNetwork(network);
 
     const marketPubkey = new PublicKey(marketAddress);
-    const founderPubkey = new PublicKey(founderAddress);
     const adminPubkey = new PublicKey(adminWallet);
 
     // Derive vault PDA
@@ -53,39 +61,43 @@ export async function POST(request: NextRequest) {
     // Derive treasury PDA
     const [treasuryPda] = getTreasuryPDA(network);
 
-    // Check vault balance before draining
+    // Check vault balance before refund
     const vaultBalance = await connection.getBalance(marketVaultPda);
-    logger.info('Vault balance before drain', {
+    logger.info('Vault balance before refund', {
       vault: marketVaultPda.toBase58(),
       balance: vaultBalance / 1e9,
     });
 
-    logger.info('Building emergency drain transaction', {
+    logger.info('Building emergency refund transaction', {
       marketVault: marketVaultPda.toBase58(),
       treasury: treasuryPda.toBase58(),
     });
 
-    // Build emergency drain instruction discriminator
-    // sha256('global:emergency_drain_vault')[0..8]
+    // Build emergency refund instruction discriminator
+    // sha256('global:emergency_refund')[0..8]
     const crypto = require('crypto');
     const discriminator = crypto
       .createHash('sha256')
-      .update('global:emergency_drain_vault', 'utf8')
+      .update('global:emergency_refund', 'utf8')
       .digest()
       .subarray(0, 8);
 
+    // Args: reason (Borsh string = u32 LE length + utf8 bytes)
+    const reasonLength = Buffer.alloc(4);
+    reasonLength.writeUInt32LE(reasonBytes.length, 0);
+    const data = Buffer.concat([discriminator, reasonLength, reasonBytes]);
+
     // Build instruction
     const instruction = new TransactionInstruction({
       keys: [
         { pubkey: marketPubkey, isSigner: false, isWritable: true },
         { pubkey: marketVaultPda, isSigner: false, isWritable: true },
         { pubkey: treasuryPda, isSigner: false, isWritable: false },
-        { pubkey: founderPubkey, isSigner: false, isWritable: true },
         { pubkey: adminPubkey, isSigner: true, isWritable: true },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
       ],
       programId: programId,
-      data: discriminator,
+      data,
     });
 
     // Build transaction
@@ -104,9 +116,9 @@ export async function POST(request: NextRequest) {
       verifySignatures: false,
     });
 
-    logger.info('Emergency drain transaction prepared', {
+    logger.info('Emergency refund transaction prepared', {
       vaultBalance: vaultBalance / 1e9,
-      founderWillReceive: (vaultBalance - 890880) / 1e9, // Minus rent-exempt amount
+      refundPool: (vaultBalance - 890880) / 1e9, // Minus rent-exempt amount
     });
 
     return NextResponse.json({
@@ -115,17 +127,17 @@ export async function POST(request: NextRequest) {
         serializedTransaction: serializedTransaction.toString('base64'),
         vaultBalance: vaultBalance / 1e9,
         marketVault: marketVaultPda.toBase58(),
-        message: `Ready to drain ${vaultBalance / 1e9} SOL to founder`,
+        message: `Ready to refund ${vaultBalance / 1e9} SOL to traders`,
       },
     });
 
   } catch (error: any) {
-    logger.error('Failed to prepare emergency drain:', error);
+    logger.error('Failed to prepare emergency refund:', error);
 
     return NextResponse.json(
       {
         success: false,
-        error: 'Failed to prepare emergency drain transaction',
+        error: 'Failed to prepare emergency refund transaction',
         details: error.message || 'Unknown error',
       },
       { status: 500 }
@@ -1,10 +1,16 @@
 /**
- * API endpoint for withdrawing fees from treasury
+ * API endpoint for timelocked treasury withdrawals
  * Only admin can call this
+ *
+ * Actions:
+ * - queue (default): queue_withdrawal(amount) to an allowlisted recipient
+ * - execute: execute_withdrawal for withdrawalId once its timelock has passed
+ * - cancel: cancel_withdrawal for withdrawalId
  */
 
 import { NextRequest, NextResponse } from 'next/server';
 import {
+  AccountMeta,
   PublicKey,
   TransactionMessage,
   VersionedTransaction,
@@ -19,62 +25,132 @@ const logger = createClientLogger();
 export async function POST(request: NextRequest) {
   try {
     const body = await request.json();
-    const { admin, recipient, amount, network } = body;
+    const { admin, recipient, amount, withdrawalId, action = 'queue', network } = body;
 
     // Validate inputs
-    if (!admin || !recipient || !amount) {
+    if (!admin || (action === 'queue' ? !recipient || !amount : withdrawalId === undefined)) {
       return NextResponse.json(
         {
           success: false,
-          error: 'Missing required fields: admin, recipient, amount',
+          error: action === 'queue'
+            ? 'Missing required fields: admin, recipient, amount'
+            : 'Missing required fields: admin, withdrawalId',
         },
         { status: 400 }
       );
     }
 
-    logger.info('Preparing withdraw fees transaction', {
+    if (!['queue', 'execute', 'cancel'].includes(action)) {
+      return NextResponse.json(
+        { success: false, error: 'Invalid action: expected queue, execute or cancel' },
+        { status: 400 }
+      );
+    }
+
+    logger.info('Preparing treasury withdrawal transaction', {
+      action,
       admin,
       recipient,
       amount,
+      withdrawalId,
       network,
     });
 
-    // Convert addresses to PublicKey
     const adminPubkey = new PublicKey(admin);
-    const recipientPubkey = new PublicKey(recipient);
 
     // Derive Treasury PDA
-    const [treasuryPda, treasuryBump] = getTreasuryPDA();
+    const [treasuryPda] = getTreasuryPDA(network);
 
     // Get connection
     const connection = await getSolanaConnection(network);
 
-    // Build withdraw_fees instruction
-    // Calculate discriminator: sha256("global:withdraw_fees")[0..8]
+    // Get program ID
+    const { PROGRAM_ID } = await import('@/config/solana');
+
+    // Withdrawal id: queue uses treasury.next_withdrawal_id
+    // Treasury layout: 8 disc + 32 admin + 8 total_fees + 1 bump + 8 claim_period + 1 paused
+    // + 8 withdrawal_delay + 8 epoch_cap + 8 withdrawal_epoch + 8 epoch_withdrawn + 8 total_withdrawn
+    let id: bigint;
+    if (action === 'queue') {
+      const treasuryInfo = await connection.getAccountInfo(treasuryPda);
+      if (!treasuryInfo || treasuryInfo.data.length < 106) {
+        throw new Error('Treasury not migrated to the withdrawal policy layout');
+      }
+      id = treasuryInfo.data.readBigUInt64LE(98);
+    } else {
+      id = BigInt(withdrawalId);
+    }
+
+    const idBytes = Buffer.alloc(8);
+    idBytes.writeBigUInt64LE(id, 0);
+    const [withdrawalPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('withdrawal'), idBytes],
+      PROGRAM_ID
+    );
+
+    // Calculate discriminator: sha256("global:<instruction>")[0..8]
     const crypto = require('crypto');
+    const instructionName = `${action}_withdrawal`;
     const discriminator = crypto
       .createHash('sha256')
-      .update('global:withdraw_fees', 'utf8')
+      .update(`global:${instructionName}`, 'utf8')
       .digest()
       .subarray(0, 8);
 
-    // Args: amount (u64 = 8 bytes)
-    const data = Buffer.alloc(8 + 8);
-    discriminator.copy(data, 0);
-    data.writeBigUInt64LE(BigInt(amount), 8);
+    let data: Buffer;
+    let keys: AccountMeta[];
+    let recipientPubkey: PublicKey | undefined;
 
-    // Get program ID
-    const { PROGRAM_ID } = await import('@/config/solana');
+    if (action === 'queue') {
+      recipientPubkey = new PublicKey(recipient);
 
-    // Create instruction
-    const { TransactionInstruction } = await import('@solana/web3.js');
-    const withdrawFeesIx = new TransactionInstruction({
-      keys: [
-        { pubkey: treasuryPda, isSigner: false, isWritable: true },     // treasury
+      // Args: amount (u64 = 8 bytes)
+      data = Buffer.alloc(8 + 8);
+      discriminator.copy(data, 0);
+      data.writeBigUInt64LE(BigInt(amount), 8);
+
+      keys = [
+        { pubkey: treasuryPda, isSigner: false, isWritable: true },      // treasury
+        { pubkey: withdrawalPda, isSigner: false, isWritable: true },    // withdrawal
         { pubkey: adminPubkey, isSigner: true, isWritable: true },       // admin
-        { pubkey: recipientPubkey, isSigner: false, isWritable: true },  // recipient
+        { pubkey: recipientPubkey, isSigner: false, isWritable: false }, // recipient
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }, // system_program
-      ],
+      ];
+    } else if (action === 'execute') {
+      // Recipient is read from the pending withdrawal (8 disc + 8 id)
+      const withdrawalInfo = await connection.getAccountInfo(withdrawalPda);
+      if (!withdrawalInfo) {
+        throw new Error(`Withdrawal #${id} not found`);
+      }
+      recipientPubkey = new PublicKey(withdrawalInfo.data.subarray(16, 48));
+
+      // Staking pool PDA (may not exist yet; reserves undistributed staker rewards)
+      const [stakingPoolPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('staking_pool')],
+        PROGRAM_ID
+      );
+
+      data = Buffer.from(discriminator);
+      keys = [
+        { pubkey: treasuryPda, isSigner: false, isWritable: true },     // treasury
+        { pubkey: withdrawalPda, isSigner: false, isWritable: true },   // withdrawal
+        { pubkey: adminPubkey, isSigner: true, isWritable: true },      // admin
+        { pubkey: recipientPubkey, isSigner: false, isWritable: true }, // recipient
+        { pubkey: stakingPoolPda, isSigner: false, isWritable: false }, // staking_pool
+      ];
+    } else {
+      data = Buffer.from(discriminator);
+      keys = [
+        { pubkey: treasuryPda, isSigner: false, isWritable: false },  // treasury
+        { pubkey: withdrawalPda, isSigner: false, isWritable: true }, // withdrawal
+        { pubkey: adminPubkey, isSigner: true, isWritable: true },    // admin
+      ];
+    }
+
+    // Create instruction
+    const { TransactionInstruction } = await import('@solana/web3.js');
+    const withdrawalIx = new TransactionInstruction({
+      keys,
       programId: PROGRAM_ID,
       data,
     });
@@ -86,7 +162,7 @@ export async function POST(request: NextRequest) {
     const messageV0 = new TransactionMessage({
       payerKey: adminPubkey,
       recentBlockhash: blockhash,
-      instructions: [withdrawFeesIx],
+      instructions: [withdrawalIx],
     }).compileToV0Message();
 
     const transaction = new VersionedTransaction(messageV0);
@@ -94,11 +170,12 @@ export async function POST(request: NextRequest) {
     // Serialize transaction for client-side signing
     const serializedTransaction = Buffer.from(transaction.serialize()).toString('base64');
 
-    logger.info('Withdraw fees transaction prepared', {
+    logger.info('Treasury withdrawal transaction prepared', {
+      action,
+      withdrawalId: id.toString(),
       treasuryPda: treasuryPda.toBase58(),
       admin: adminPubkey.toBase58(),
-      recipient: recipientPubkey.toBase58(),
-      amount: amount.toString(),
+      recipient: recipientPubkey?.toBase58(),
       serializedLength: serializedTransaction.length,
       lastValidBlockHeight,
     });
@@ -108,14 +185,14 @@ export async function POST(request: NextRequest) {
       data: {
         serializedTransaction,
         treasuryPda: treasuryPda.toBase58(),
-        recipient: recipientPubkey.toBase58(),
-        amount: amount.toString(),
+        withdrawalId: id.toString(),
+        recipient: recipientPubkey?.toBase58(),
         lastValidBlockHeight,
       },
     });
 
   } catch (error) {
-    logger.error('Failed to prepare withdraw fees transaction:', error);
+    logger.error('Failed to prepare treasury withdrawal transaction:', error);
 
     console.error('=== FULL ERROR DETAILS ===');
     console.error('Error:', error);
@@ -126,7 +203,7 @@ export async function POST(request: NextRequest) {
     return NextResponse.json(
       {
         success: false,
-        error: 'Failed to prepare withdraw fees transaction',
+        error: 'Failed to prepare treasury withdrawal transaction',
         details: error instanceof Error ? error.message : 'Unknown error',
         stack: error instanceof Error ? error.stack : undefined
       },
@@ -851,14 +851,9 @@ export default function MarketDetailsPage() {
   const handleInitTeamVesting = async () => {
     if (!market || !mergedOnchainData?.success || !primaryWallet) return;
 
-    // For now, use a placeholder for total token supply
-    // In production, this should be calculated from the actual token created
-    const totalTokenSupply = 1_000_000_000; // 1 billion tokens as placeholder
-
     const result = await initVesting({
       marketAddress: market.marketAddress,
       teamWallet: mergedOnchainData.data.founder,
-      totalTokenSupply,
     });
 
     if (result.success) {
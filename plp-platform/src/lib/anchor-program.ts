@@ -152,6 +152,61 @@ export function getPositionPDA(
   );
 }
 
+/**
+ * Derive a wallet's Reputation PDA (updated by claim_rewards)
+ *
+ * @param walletPubkey - Predictor wallet
+ * @param network - Network to use (defaults to devnet)
+ * @returns Reputation PDA and bump
+ */
+export function getReputationPDA(
+  walletPubkey: PublicKey,
+  network?: 'devnet' | 'mainnet-beta'
+): [PublicKey, number] {
+  const programId = getProgramIdForNetwork(network);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('reputation'), walletPubkey.toBytes()],
+    programId
+  );
+}
+
+/**
+ * Derive a founder's FounderReputation PDA (updated by resolve_market)
+ *
+ * @param founderPubkey - Founder wallet
+ * @param network - Network to use (defaults to devnet)
+ * @returns Founder reputation PDA and bump
+ */
+export function getFounderReputationPDA(
+  founderPubkey: PublicKey,
+  network?: 'devnet' | 'mainnet-beta'
+): [PublicKey, number] {
+  const programId = getProgramIdForNetwork(network);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('founder_reputation'), founderPubkey.toBytes()],
+    programId
+  );
+}
+
+/**
+ * Vesting schedule argument (matches the on-chain VestingSchedule)
+ * All-zero = default schedule (12 months linear, no cliff)
+ */
+export interface VestingScheduleParams {
+  curve: 'linear' | 'cliff' | 'monthlyStep';
+  cliffSeconds: number;
+  durationSeconds: number;
+}
+
+const DEFAULT_VESTING_SCHEDULE: VestingScheduleParams = {
+  curve: 'linear',
+  cliffSeconds: 0,
+  durationSeconds: 0,
+};
+
+// Borsh enum index of each VestingCurve variant
+const VESTING_CURVE_INDEX = { linear: 0, cliff: 1, monthlyStep: 2 } as const;
+
 /**
  * Build create market transaction (manually, without Anchor SDK)
  *
@@ -164,6 +219,8 @@ export async function buildCreateMarketTransaction(params: {
   targetPool: number; // in lamports (5/10/15 SOL)
   marketDuration: number; // in days
   metadataUri: string;
+  teamSchedule?: VestingScheduleParams;
+  founderSchedule?: VestingScheduleParams;
   wallet?: any;
   network?: 'devnet' | 'mainnet-beta';
 }) {
@@ -215,9 +272,11 @@ export async function buildCreateMarketTransaction(params: {
     .subarray(0, 8);
 
   // Serialize instruction data
-  // Format: [discriminator(8 bytes), ipfs_cid(string), target_pool(u64), expiry_time(i64), metadata_uri(string)]
+  // Format: [discriminator(8 bytes), ipfs_cid(string), target_pool(u64), expiry_time(i64), metadata_uri(string),
+  //          team_schedule(VestingSchedule), founder_schedule(VestingSchedule)]
   const ipfsCidBytes = Buffer.from(params.ipfsCid, 'utf8');
   const metadataUriBytes = Buffer.from(params.metadataUri, 'utf8');
+  const VESTING_SCHEDULE_SIZE = 1 + 8 + 8; // curve(u8 enum) + cliff_seconds(i64) + duration_seconds(i64)
 
   // Create data buffer
   const data = Buffer.alloc(
@@ -225,7 +284,8 @@ export async function buildCreateMarketTransaction(params: {
     4 + ipfsCidBytes.length + // ipfs_cid (u32 length prefix + string)
     8 + // target_pool (u64)
     8 + // expiry_time (i64)
-    4 + metadataUriBytes.length // metadata_uri (u32 length prefix + string)
+    4 + metadataUriBytes.length + // metadata_uri (u32 length prefix + string)
+    VESTING_SCHEDULE_SIZE * 2 // team_schedule + founder_schedule
   );
 
   let offset = 0;
@@ -252,6 +312,37 @@ export async function buildCreateMarketTransaction(params: {
   data.writeUInt32LE(metadataUriBytes.length, offset);
   offset += 4;
   metadataUriBytes.copy(data, offset);
+  offset += metadataUriBytes.length;
+
+  // Write team_schedule and founder_schedule
+  for (const schedule of [
+    params.teamSchedule ?? DEFAULT_VESTING_SCHEDULE,
+    params.founderSchedule ?? DEFAULT_VESTING_SCHEDULE,
+  ]) {
+    data.writeUInt8(VESTING_CURVE_INDEX[schedule.curve], offset);
+    offset += 1;
+    data.writeBigInt64LE(BigInt(schedule.cliffSeconds), offset);
+    offset += 8;
+    data.writeBigInt64LE(BigInt(schedule.durationSeconds), offset);
+    offset += 8;
+  }
+
+  // Create transaction with dynamic RPC endpoint
+  const connection = new Connection(rpcEndpoint, 'confirmed');
+
+  // Founder profile (optional trailing account): required on-chain for target pools
+  // at or above Treasury.verification_min_target, and counts the founder's markets
+  const [founderProfilePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('founder_profile'), params.founder.toBuffer()],
+    programId
+  );
+  const founderProfileInfo = await connection.getAccountInfo(founderProfilePda);
+
+  // Founder bond PDA (refundable bond locked at creation)
+  const [founderBondPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('founder_bond'), marketPda.toBuffer()],
+    programId
+  );
 
   // Create instruction
   const { TransactionInstruction: TxInstruction } = await import('@solana/web3.js');
@@ -261,14 +352,16 @@ export async function buildCreateMarketTransaction(params: {
       { pubkey: marketVaultPda, isSigner: false, isWritable: true },   // market_vault (NEW!)
       { pubkey: treasuryPda, isSigner: false, isWritable: true },      // treasury
       { pubkey: params.founder, isSigner: true, isWritable: true },    // founder
+      { pubkey: founderBondPda, isSigner: false, isWritable: true },   // founder_bond (1% of target pool)
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }, // system_program
+      ...(founderProfileInfo
+        ? [{ pubkey: founderProfilePda, isSigner: false, isWritable: true }] // founder_profile
+        : []),
     ],
     programId: programId,
     data,
   });
 
-  // Create transaction with dynamic RPC endpoint
-  const connection = new Connection(rpcEndpoint, 'confirmed');
   const { blockhash } = await connection.getLatestBlockhash();
 
   const transaction = new Transaction({
@@ -459,8 +552,9 @@ export async function buildClaimRewardsTransaction(params: {
 }) {
   const program = getProgram(params.wallet);
 
-  // Derive position PDA
+  // Derive position and reputation PDAs
   const [positionPda] = getPositionPDA(params.market, params.user);
+  const [reputationPda] = getReputationPDA(params.user);
 
   // Build transaction
   const tx = await program.methods
@@ -470,6 +564,7 @@ export async function buildClaimRewardsTransaction(params: {
       position: positionPda,
       user: params.user,
       systemProgram: SystemProgram.programId,
+      reputation: reputationPda,
     })
     .transaction();
 
@@ -492,8 +587,16 @@ export async function buildClosePositionTransaction(params: {
 }) {
   const program = getProgram(params.wallet);
 
-  // Derive position PDA
+  // Derive position and reputation PDAs
   const [positionPda] = getPositionPDA(params.market, params.user);
+  const [reputationPda] = getReputationPDA(params.user);
+
+  // Founder milestones (optional): YesWins positions only close once all are attested
+  const [founderMilestonesPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('founder_milestones'), params.market.toBytes()],
+    program.programId
+  );
+  const founderMilestonesInfo = await program.provider.connection.getAccountInfo(founderMilestonesPda);
 
   // Build transaction
   const tx = await program.methods
@@ -501,7 +604,10 @@ export async function buildClosePositionTransaction(params: {
     .accounts({
       market: params.market,
       position: positionPda,
+      reputation: reputationPda,
       user: params.user,
+      systemProgram: SystemProgram.programId,
+      founderMilestones: founderMilestonesInfo ? founderMilestonesPda : null,
     })
     .transaction();
 
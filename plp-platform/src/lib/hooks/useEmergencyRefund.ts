@@ -1,6 +1,6 @@
 /**
- * useEmergencyDrain Hook
- * Handles emergency vault drainage (admin only)
+ * useEmergencyRefund Hook
+ * Forces a market into Refund so traders can reclaim their SOL (admin only)
  */
 
 import { useState } from 'react';
@@ -10,32 +10,32 @@ import { useNetwork } from './useNetwork';
 import { useSignAndSendTransaction, useWallets, useStandardWallets } from '@privy-io/react-auth/solana';
 import bs58 from 'bs58';
 
-export function useEmergencyDrain() {
-  const [isDraining, setIsDraining] = useState(false);
+export function useEmergencyRefund() {
+  const [isRefunding, setIsRefunding] = useState(false);
   const { primaryWallet } = useWallet();
   const { network } = useNetwork();
   const { wallets } = useWallets(); // External wallets
   const { wallets: standardWallets } = useStandardWallets(); // Standard wallet interface (includes embedded)
   const { signAndSendTransaction } = useSignAndSendTransaction();
 
-  const drainVault = async (params: {
+  const refundMarket = async (params: {
     marketAddress: string;
-    founderAddress: string;
+    reason: string;
   }): Promise<{ success: boolean; signature?: string; error?: any }> => {
     if (!primaryWallet) {
       return { success: false, error: 'No wallet connected' };
     }
 
     try {
-      setIsDraining(true);
+      setIsRefunding(true);
 
-      console.log('🔧 Preparing emergency vault drain...');
-      const prepareResponse = await fetch('/api/treasury/emergency-drain', {
+      console.log('🔧 Preparing emergency refund...');
+      const prepareResponse = await fetch('/api/treasury/emergency-refund', {
         method: 'POST',
         headers: { 'Content-Type': 'application/json' },
         body: JSON.stringify({
           marketAddress: params.marketAddress,
-          founderAddress: params.founderAddress,
+          reason: params.reason,
           adminWallet: primaryWallet.address,
           network,
         }),
@@ -44,11 +44,11 @@ export function useEmergencyDrain() {
       const prepareResult = await prepareResponse.json();
 
       if (!prepareResult.success) {
-        console.error('❌ Failed to prepare emergency drain:', prepareResult.error);
+        console.error('❌ Failed to prepare emergency refund:', prepareResult.error);
         return { success: false, error: prepareResult.error };
       }
 
-      console.log('✅ Emergency drain transaction prepared');
+      console.log('✅ Emergency refund transaction prepared');
       console.log(`💰 Vault balance: ${prepareResult.data.vaultBalance} SOL`);
 
       let signature;
@@ -110,22 +110,22 @@ export function useEmergencyDrain() {
         return { success: false, error: errorMessage };
       }
 
-      console.log('🎉 Vault drained successfully!');
+      console.log('🎉 Market switched to refund mode!');
       return {
         success: true,
         signature,
       };
 
     } catch (error) {
-      console.error('❌ Emergency drain error:', error);
+      console.error('❌ Emergency refund error:', error);
       return { success: false, error };
     } finally {
-      setIsDraining(false);
+      setIsRefunding(false);
     }
   };
 
   return {
-    drainVault,
-    isDraining,
+    refundMarket,
+    isRefunding,
   };
 }
@@ -24,7 +24,6 @@ export function useTeamVesting() {
   const initVesting = async (params: {
     marketAddress: string;
     teamWallet: string;
-    totalTokenSupply: number;
   }): Promise<{ success: boolean; signature?: string; error?: any }> => {
     if (!primaryWallet) {
       return { success: false, error: 'No wallet connected' };
@@ -41,7 +40,6 @@ export function useTeamVesting() {
           marketAddress: params.marketAddress,
           teamWallet: params.teamWallet,
           callerWallet: primaryWallet.address,
-          totalTokenSupply: params.totalTokenSupply,
           network,
         }),
       });